@@ -0,0 +1,50 @@
+//! Times a * b + c * d, and the chain a * b * c * d, evaluated eagerly and
+//! through the lazy expressions in matrix::expr. Run with `cargo bench`.
+
+extern crate matrix;
+
+use std::hint::black_box;
+use std::time::Instant;
+
+use matrix::expr::lazy;
+use matrix::Matrix;
+
+const ITERATIONS: u32 = 10_000_000;
+
+fn time<F>(name: &str, mut f: F) where F: FnMut() -> Matrix<f64> {
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(f());
+    }
+    let elapsed = start.elapsed();
+
+    println!("{:>11}: {:?} total, {:.2} ns per evaluation", name, elapsed, elapsed.as_nanos() as f64 / ITERATIONS as f64);
+}
+
+fn main() {
+    let a = Matrix::new(1.0, 2.0, 3.0, 4.0);
+    let b = Matrix::new(0.5, -1.0, 0.25, 2.0);
+    let c = Matrix::new(-3.0, 1.5, 2.0, 0.0);
+    let d = Matrix::new(2.0, 0.0, 1.0, 2.0);
+
+    time("eager", || {
+        let (a, b, c, d) = black_box((a, b, c, d));
+
+        a * b + c * d
+    });
+    time("lazy", || {
+        let (a, b, c, d) = black_box((a, b, c, d));
+
+        (lazy(&a) * lazy(&b) + lazy(&c) * lazy(&d)).eval()
+    });
+    time("eager chain", || {
+        let (a, b, c, d) = black_box((a, b, c, d));
+
+        a * b * c * d
+    });
+    time("lazy chain", || {
+        let (a, b, c, d) = black_box((a, b, c, d));
+
+        (lazy(&a) * lazy(&b) * lazy(&c) * lazy(&d)).eval()
+    });
+}
@@ -0,0 +1,505 @@
+use std::fmt;
+use std::fmt::Display;
+use std::ops::{Add, Mul};
+
+use geometry::Aabb;
+use num::LeBytes;
+use Matrix;
+use Matrix3;
+use Matrix4;
+use Point2;
+use Rotation2;
+use Vector;
+use Vector3;
+
+/// Represents an affine map x -> linear * x + translation.
+///
+/// Applied to a Point2 the translation takes effect; applied to a Vector
+/// (a direction or displacement) only the linear part does.
+///
+/// Laid out as repr(C), the linear part's four entries row by row and then
+/// the translation.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(C)]
+pub struct Affine2<T> where T: Copy {
+    linear: Matrix<T>,
+    translation: Vector<T>
+}
+
+/// Represents a rigid motion: a rotation followed by a translation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Isometry2 {
+    rotation: Rotation2,
+    translation: Vector<f64>
+}
+
+/// Represents a 3D rigid motion: a rotation matrix followed by a translation.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Isometry3 {
+    rotation: Matrix3<f64>,
+    translation: Vector3<f64>
+}
+
+/// A stack of affine transforms, in the style of a 2D canvas.
+///
+/// Transforms applied after a push are discarded by the matching pop, so
+/// nested drawing code can scope its own transforms.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct TransformStack {
+    saved: Vec<Affine2<f64>>,
+    current: Affine2<f64>
+}
+
+
+// Vanilla Methods
+
+impl<T> Affine2<T> where T: Copy {
+    pub fn new(linear: Matrix<T>, translation: Vector<T>) -> Affine2<T> {
+        Affine2 {
+            linear,
+            translation
+        }
+    }
+
+    pub fn linear(&self) -> Matrix<T> {
+        self.linear
+    }
+
+    pub fn translation(&self) -> Vector<T> {
+        self.translation
+    }
+
+    /// This transform as an SVG transform, matrix(a,b,c,d,e,f) mapping (x, y)
+    /// to (ax + cy + e, bx + dy + f).
+    ///
+    pub fn to_svg_transform(&self) -> String where T: Display {
+        format!("matrix({},{},{},{},{},{})",
+                self.linear.a, self.linear.c, self.linear.b, self.linear.d, self.translation.x, self.translation.y)
+    }
+
+    /// The linear part's bytes followed by the translation's, as in
+    /// Matrix::to_bytes and Vector::to_bytes, so 6 * T::SIZE bytes in all.
+    ///
+    pub fn to_bytes(&self) -> Vec<u8> where T: LeBytes {
+        let mut bytes = self.linear.to_bytes();
+        bytes.extend(self.translation.to_bytes());
+
+        bytes
+    }
+
+    /// The transform encoded by to_bytes, or None if bytes has the wrong length.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Affine2<T>> where T: LeBytes {
+        if bytes.len() != 6 * T::SIZE {
+            return None;
+        }
+
+        let (linear, translation) = bytes.split_at(4 * T::SIZE);
+
+        Some(Affine2::new(Matrix::from_bytes(linear)?, Vector::from_bytes(translation)?))
+    }
+}
+
+impl Affine2<f64> {
+    pub fn identity() -> Affine2<f64> {
+        Affine2::from_linear(Matrix::new(1.0, 0.0, 0.0, 1.0))
+    }
+
+    pub fn from_linear(linear: Matrix<f64>) -> Affine2<f64> {
+        Affine2::new(linear, Vector::new(0.0, 0.0))
+    }
+
+    pub fn from_translation(translation: Vector<f64>) -> Affine2<f64> {
+        Affine2::new(Matrix::new(1.0, 0.0, 0.0, 1.0), translation)
+    }
+
+    /// The inverse x -> L^-1 (x - t), or None if the linear part L is singular.
+    pub fn inverse(&self) -> Option<Affine2<f64>> {
+        let linear = self.linear.inverse()?;
+
+        Some(Affine2::new(linear, linear * self.translation * -1.0))
+    }
+
+    /// The inverse transpose of the linear part, for transforming surface
+    /// normals, or None if the linear part is singular.
+    ///
+    pub fn normal_matrix(&self) -> Option<Matrix<f64>> {
+        self.linear.normal_matrix()
+    }
+
+    /// The transform taking the corners of from onto the matching corners of to.
+    pub fn rect_to_rect(from: &Aabb, to: &Aabb) -> Affine2<f64> {
+        Affine2::viewport(from, to, false, false)
+    }
+
+    /// The transform taking a world rectangle onto a screen rectangle.
+    ///
+    /// With flip_y the world's upward y axis maps to the screen's downward one.
+    /// With preserve_aspect both axes are scaled equally, by the largest factor
+    /// that keeps the world inside the screen, and the result is centred.
+    ///
+    pub fn viewport(world: &Aabb, screen: &Aabb, flip_y: bool, preserve_aspect: bool) -> Affine2<f64> {
+        let (world_size, screen_size) = (world.size(), screen.size());
+        let mut sx = screen_size.x / world_size.x;
+        let mut sy = screen_size.y / world_size.y;
+
+        if preserve_aspect {
+            sx = sx.min(sy);
+            sy = sx;
+        }
+        if flip_y {
+            sy = -sy;
+        }
+
+        let linear = Matrix::new(sx, 0.0, 0.0, sy);
+
+        Affine2::new(linear, screen.center() - linear * world.center())
+    }
+}
+
+impl Isometry2 {
+    pub fn new(rotation: Rotation2, translation: Vector<f64>) -> Isometry2 {
+        Isometry2 {
+            rotation,
+            translation
+        }
+    }
+
+    pub fn rotation(&self) -> Rotation2 {
+        self.rotation
+    }
+
+    pub fn translation(&self) -> Vector<f64> {
+        self.translation
+    }
+
+    pub fn inverse(&self) -> Isometry2 {
+        let rotation = self.rotation.inverse();
+
+        Isometry2::new(rotation, (rotation * self.translation) * -1.0)
+    }
+
+    pub fn to_affine(&self) -> Affine2<f64> {
+        Affine2::new(self.rotation.matrix(), self.translation)
+    }
+}
+
+impl Isometry3 {
+    /// The motion p -> rotation * p + translation; rotation should be orthogonal
+    /// with determinant 1.
+    ///
+    pub fn new(rotation: Matrix3<f64>, translation: Vector3<f64>) -> Isometry3 {
+        Isometry3 {
+            rotation,
+            translation
+        }
+    }
+
+    /// The right-handed view transform of a camera at eye looking at target:
+    /// target lands on the -z axis, and up, projected, on the +y half-plane.
+    ///
+    pub fn look_at_rh(eye: Vector3<f64>, target: Vector3<f64>, up: Vector3<f64>) -> Isometry3 {
+        let forward = (target - eye).normalize();
+        let side = forward.cross(up).normalize();
+
+        Isometry3::from_camera_axes(eye, side, side.cross(forward), forward * -1.0)
+    }
+
+    /// The left-handed view transform of a camera at eye looking at target:
+    /// target lands on the +z axis, and up, projected, on the +y half-plane.
+    ///
+    pub fn look_at_lh(eye: Vector3<f64>, target: Vector3<f64>, up: Vector3<f64>) -> Isometry3 {
+        let forward = (target - eye).normalize();
+        let side = up.cross(forward).normalize();
+
+        Isometry3::from_camera_axes(eye, side, forward.cross(side), forward)
+    }
+
+    pub fn rotation(&self) -> Matrix3<f64> {
+        self.rotation
+    }
+
+    pub fn translation(&self) -> Vector3<f64> {
+        self.translation
+    }
+
+    pub fn inverse(&self) -> Isometry3 {
+        let rotation = self.rotation.transpose();
+
+        Isometry3::new(rotation, (rotation * self.translation) * -1.0)
+    }
+
+    pub fn transform_point(&self, p: Vector3<f64>) -> Vector3<f64> {
+        self.rotation * p + self.translation
+    }
+
+    /// The homogeneous 4x4 matrix [[rotation, translation], [0, 1]].
+    pub fn to_matrix4(&self) -> Matrix4<f64> {
+        let (r, t) = (self.rotation, self.translation);
+        let row = |i: usize| [r.get(i, 0), r.get(i, 1), r.get(i, 2)];
+        let (r0, r1, r2) = (row(0), row(1), row(2));
+
+        Matrix4::from_rows([
+            [r0[0], r0[1], r0[2], t.x()],
+            [r1[0], r1[1], r1[2], t.y()],
+            [r2[0], r2[1], r2[2], t.z()],
+            [0.0,   0.0,   0.0,   1.0  ]
+        ])
+    }
+
+    /// The view transform taking the camera axes at eye to the coordinate axes.
+    fn from_camera_axes(eye: Vector3<f64>, x: Vector3<f64>, y: Vector3<f64>, z: Vector3<f64>) -> Isometry3 {
+        let rotation = Matrix3::from_columns(x, y, z).transpose();
+
+        Isometry3::new(rotation, (rotation * eye) * -1.0)
+    }
+}
+
+impl TransformStack {
+    /// A stack whose current transform is the identity.
+    pub fn new() -> TransformStack {
+        TransformStack {
+            saved: Vec::new(),
+            current: Affine2::identity()
+        }
+    }
+
+    /// Saves the current transform, to be restored by the next pop.
+    pub fn push(&mut self) {
+        self.saved.push(self.current);
+    }
+
+    /// Restores the most recently pushed transform, returning the one discarded.
+    ///
+    /// Returns None, leaving the stack unchanged, if nothing has been pushed.
+    ///
+    pub fn pop(&mut self) -> Option<Affine2<f64>> {
+        self.saved.pop().map(|previous| {
+            let discarded = self.current;
+            self.current = previous;
+            discarded
+        })
+    }
+
+    pub fn current(&self) -> Affine2<f64> {
+        self.current
+    }
+
+    /// Composes t onto the current transform, so that it acts on coordinates first.
+    pub fn apply(&mut self, t: Affine2<f64>) {
+        self.current = self.current * t;
+    }
+}
+
+impl Default for TransformStack {
+    fn default() -> TransformStack {
+        TransformStack::new()
+    }
+}
+
+// Display Methods
+
+impl<T> fmt::Display for Affine2<T> where T: Copy + Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[[{} {} {}], [{} {} {}]]",
+               self.linear.a, self.linear.b, self.translation.x,
+               self.linear.c, self.linear.d, self.translation.y)
+    }
+}
+
+// Operator Methods
+
+/// Implementation of Affine2 * Affine2, applying rhs first.
+impl<T> Mul<Affine2<T>> for Affine2<T> where T: Copy + Mul<Output=T> + Add<Output=T> {
+    type Output = Affine2<T>;
+
+    fn mul(self, rhs: Affine2<T>) -> Affine2<T> {
+        Affine2::new(
+            self.linear * rhs.linear,
+            self.linear * rhs.translation + self.translation
+        )
+    }
+}
+
+/// Implementation of Affine2 * Point2.
+impl<T> Mul<Point2<T>> for Affine2<T> where T: Copy + Mul<Output=T> + Add<Output=T> {
+    type Output = Point2<T>;
+
+    fn mul(self, rhs: Point2<T>) -> Point2<T> {
+        self.linear * rhs + self.translation
+    }
+}
+
+/// Implementation of Affine2 * Vector, ignoring the translation.
+impl<T> Mul<Vector<T>> for Affine2<T> where T: Copy + Mul<Output=T> + Add<Output=T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: Vector<T>) -> Vector<T> {
+        self.linear * rhs
+    }
+}
+
+/// Implementation of Isometry2 * Isometry2, applying rhs first.
+impl Mul<Isometry2> for Isometry2 {
+    type Output = Isometry2;
+
+    fn mul(self, rhs: Isometry2) -> Isometry2 {
+        Isometry2::new(
+            self.rotation * rhs.rotation,
+            self.rotation * rhs.translation + self.translation
+        )
+    }
+}
+
+/// Implementation of Isometry2 * Point2.
+impl Mul<Point2<f64>> for Isometry2 {
+    type Output = Point2<f64>;
+
+    fn mul(self, rhs: Point2<f64>) -> Point2<f64> {
+        self.to_affine() * rhs
+    }
+}
+
+/// Implementation of Isometry2 * Vector, ignoring the translation.
+impl Mul<Vector<f64>> for Isometry2 {
+    type Output = Vector<f64>;
+
+    fn mul(self, rhs: Vector<f64>) -> Vector<f64> {
+        self.rotation * rhs
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use geometry::Aabb;
+    use linear_transforms::rotation;
+    use Affine2;
+    use Isometry2;
+    use Isometry3;
+    use Matrix;
+    use Matrix4;
+    use Point2;
+    use Rotation2;
+    use TransformStack;
+    use Vector;
+    use Vector3;
+
+    #[test]
+    fn affine() {
+        let t = Affine2::new(Matrix::new(0, -1, 1, 0), Vector::new(10, 20));
+        let p = Point2::new(1, 2);
+        let v = Vector::new(1, 2);
+
+        assert_eq!(Point2::new(8, 21), t * p);
+        assert_eq!(Vector::new(-2, 1), t * v);
+        assert_eq!(t * (t * p), (t * t) * p);
+        assert_eq!("[[0 -1 10], [1 0 20]]", format!("{}", t));
+
+        let shift = Affine2::from_translation(Vector::new(1.0, 0.0));
+        assert_eq!(Point2::new(1.0, 2.0), shift * Point2::new(0.0, 2.0));
+        assert_eq!(Vector::new(0.0, 2.0), shift * Vector::new(0.0, 2.0));
+    }
+
+    #[test]
+    fn isometry() {
+        let t = Isometry2::new(Rotation2::new(0.7), Vector::new(3.0, -1.0));
+        let p = Point2::new(2.0, 5.0);
+
+        assert_eq!(t.to_affine() * p, t * p);
+        assert!(((t.inverse() * (t * p)) - p).length() < 1e-12);
+        assert!(((t * t.inverse()) * p - p).length() < 1e-12);
+        assert!(((t * t) * p - t * (t * p)).length() < 1e-12);
+        assert_eq!(Rotation2::new(0.7) * Vector::new(1.0, 0.0), t * Vector::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn look_at() {
+        let close = |a: Vector3<f64>, b: Vector3<f64>| (a - b).length() < 1e-12;
+        let (eye, target, up) = (Vector3::new(1.0, 2.0, 3.0), Vector3::new(4.0, 6.0, 3.0), Vector3::new(0.0, 0.0, 1.0));
+
+        let rh = Isometry3::look_at_rh(eye, target, up);
+        assert!(close(Vector3::new(0.0, 0.0, 0.0), rh.transform_point(eye)));
+        assert!(close(Vector3::new(0.0, 0.0, -5.0), rh.transform_point(target)));
+        assert!(rh.transform_point(eye + up).y() > 0.0);
+        assert!(close(eye, rh.inverse().transform_point(rh.transform_point(eye))));
+        assert!(close(target, rh.inverse().transform_point(Vector3::new(0.0, 0.0, -5.0))));
+
+        let lh = Isometry3::look_at_lh(eye, target, up);
+        assert!(close(Vector3::new(0.0, 0.0, 5.0), lh.transform_point(target)));
+        assert!(lh.transform_point(eye + up).y() > 0.0);
+
+        // forward x up points right in a right-handed world, up x forward in a
+        // left-handed one, and both cameras put right on +x
+        assert!(close(Vector3::new(1.0, 0.0, 0.0), rh.transform_point(eye + Vector3::new(0.8, -0.6, 0.0))));
+        assert!(close(Vector3::new(1.0, 0.0, 0.0), lh.transform_point(eye + Vector3::new(-0.8, 0.6, 0.0))));
+
+        let p = Vector3::new(-1.0, 0.5, 2.0);
+        assert!(close(rh.transform_point(p), Matrix4::look_at_rh(eye, target, up).project_point(p)));
+        assert!(close(lh.transform_point(p), Matrix4::look_at_lh(eye, target, up).project_point(p)));
+    }
+
+    #[test]
+    fn viewports() {
+        let world = Aabb::new(Vector::new(-1.0, -1.0), Vector::new(1.0, 1.0));
+        let screen = Aabb::new(Vector::new(0.0, 0.0), Vector::new(400.0, 200.0));
+
+        let stretch = Affine2::rect_to_rect(&world, &screen);
+        assert_eq!(Point2::new(0.0, 0.0),     stretch * Point2::new(-1.0, -1.0));
+        assert_eq!(Point2::new(400.0, 200.0), stretch * Point2::new(1.0, 1.0));
+
+        let flipped = Affine2::viewport(&world, &screen, true, false);
+        assert_eq!(Point2::new(0.0, 200.0),   flipped * Point2::new(-1.0, -1.0));
+        assert_eq!(Point2::new(400.0, 0.0),   flipped * Point2::new(1.0, 1.0));
+
+        let letterboxed = Affine2::viewport(&world, &screen, true, true);
+        assert_eq!(Point2::new(200.0, 100.0), letterboxed * Point2::new(0.0, 0.0));
+        assert_eq!(Point2::new(100.0, 200.0), letterboxed * Point2::new(-1.0, -1.0));
+        assert_eq!(Point2::new(300.0, 0.0),   letterboxed * Point2::new(1.0, 1.0));
+    }
+
+    #[test]
+    fn svg() {
+        let a = Affine2::new(Matrix::new(1.0, 2.0, 3.0, 4.0), Vector::new(5.0, -6.5));
+
+        assert_eq!("matrix(1,3,2,4,5,-6.5)", a.to_svg_transform());
+        assert_eq!("matrix(1,3,2,4,0,0)", a.linear().to_svg_matrix());
+        assert_eq!("matrix(1,0,0,1,0,0)", Affine2::identity().to_svg_transform());
+    }
+
+    #[test]
+    fn bytes() {
+        let a = Affine2::new(Matrix::new(1.0f32, 2.0, 3.0, 4.0), Vector::new(5.0, -6.5));
+        let bytes = a.to_bytes();
+
+        assert_eq!(24, bytes.len());
+        assert_eq!(&2.0f32.to_le_bytes(), &bytes[4..8]);
+        assert_eq!(&(-6.5f32).to_le_bytes(), &bytes[20..]);
+        assert_eq!(Some(a), Affine2::from_bytes(&bytes));
+        assert_eq!(None, Affine2::<f32>::from_bytes(&bytes[1..]));
+    }
+
+    #[test]
+    fn stack() {
+        let shift = Affine2::from_translation(Vector::new(1.0, 0.0));
+        let double = Affine2::from_linear(Matrix::new(2.0, 0.0, 0.0, 2.0));
+        let mut stack = TransformStack::new();
+
+        assert_eq!(None, stack.pop());
+
+        stack.apply(shift);
+        stack.push();
+        stack.apply(double);
+        assert_eq!(Point2::new(3.0, 2.0), stack.current() * Point2::new(1.0, 1.0));
+
+        stack.push();
+        stack.apply(Affine2::from_linear(rotation(1.0)));
+        stack.pop();
+        assert_eq!(shift * double, stack.current());
+
+        assert_eq!(Some(shift * double), stack.pop());
+        assert_eq!(shift, stack.current());
+        assert_eq!(None, stack.pop());
+    }
+}
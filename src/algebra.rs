@@ -0,0 +1,184 @@
+use std::ops::{Add, Mul, Sub};
+
+use num::Ring;
+use symmetry::D4;
+use Isometry2;
+use Matrix;
+use Orthogonal;
+use Rotation2;
+use Vector;
+
+/// Types forming a group under Mul, with an identity and inverses.
+///
+/// Rings are in num::Ring, as square matrices form one under + and *.
+/// Orthogonal matrices form a group, as do rotations, isometries and the
+/// symmetries of the square. NonSingular is not a Group: in floating point
+/// a product of invertible matrices can come out singular, so its product
+/// is an Option.
+///
+pub trait Group: Copy + Mul<Output=Self> {
+    fn identity() -> Self;
+
+    fn inverse(&self) -> Self;
+}
+
+/// Types forming a vector space over the ring Field.
+pub trait VectorSpace: Copy + Add<Output=Self> + Sub<Output=Self> {
+    type Field: Ring;
+
+    fn zero_vector() -> Self;
+
+    fn scale(&self, factor: Self::Field) -> Self;
+}
+
+/// Vector spaces with an inner product.
+pub trait InnerProductSpace: VectorSpace {
+    fn inner(&self, other: &Self) -> Self::Field;
+}
+
+/// g raised to the power n, negative n using the inverse, by repeated
+/// squaring.
+///
+pub fn power<G>(g: G, n: i64) -> G where G: Group {
+    let mut base = if n < 0 { g.inverse() } else { g };
+    let mut n = n.unsigned_abs();
+    let mut result = G::identity();
+
+    while n > 0 {
+        if n & 1 == 1 {
+            result = result * base;
+        }
+        base = base * base;
+        n >>= 1;
+    }
+
+    result
+}
+
+
+impl Group for Orthogonal<f64> {
+    fn identity() -> Orthogonal<f64> {
+        Orthogonal::rotation(0.0)
+    }
+
+    fn inverse(&self) -> Orthogonal<f64> {
+        Orthogonal::inverse(self)
+    }
+}
+
+impl Group for Rotation2 {
+    fn identity() -> Rotation2 {
+        Rotation2::new(0.0)
+    }
+
+    fn inverse(&self) -> Rotation2 {
+        Rotation2::inverse(self)
+    }
+}
+
+impl Group for Isometry2 {
+    fn identity() -> Isometry2 {
+        Isometry2::new(Rotation2::new(0.0), Vector::new(0.0, 0.0))
+    }
+
+    fn inverse(&self) -> Isometry2 {
+        Isometry2::inverse(self)
+    }
+}
+
+impl Group for D4 {
+    fn identity() -> D4 {
+        D4::Identity
+    }
+
+    fn inverse(&self) -> D4 {
+        D4::inverse(self)
+    }
+}
+
+impl<T> VectorSpace for Vector<T> where T: Ring {
+    type Field = T;
+
+    fn zero_vector() -> Vector<T> {
+        Vector::new(T::zero(), T::zero())
+    }
+
+    fn scale(&self, factor: T) -> Vector<T> {
+        Vector::scale(self, factor)
+    }
+}
+
+impl<T> InnerProductSpace for Vector<T> where T: Ring {
+    fn inner(&self, other: &Vector<T>) -> T {
+        self.dot(*other)
+    }
+}
+
+impl<T> VectorSpace for Matrix<T> where T: Ring {
+    type Field = T;
+
+    fn zero_vector() -> Matrix<T> {
+        Matrix::new(T::zero(), T::zero(), T::zero(), T::zero())
+    }
+
+    fn scale(&self, factor: T) -> Matrix<T> {
+        Matrix::scale(self, factor)
+    }
+}
+
+/// The Frobenius inner product, the sum of the entrywise products.
+impl<T> InnerProductSpace for Matrix<T> where T: Ring {
+    fn inner(&self, other: &Matrix<T>) -> T {
+        self.a * other.a + self.b * other.b + self.c * other.c + self.d * other.d
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use algebra::{power, Group, InnerProductSpace, VectorSpace};
+    use num::{One, Ring, Zero};
+    use symmetry::{ALL, D4};
+    use Complex;
+    use Matrix;
+    use Orthogonal;
+    use Rotation2;
+    use Vector;
+
+    // x^2 - x y, written once for any ring
+    fn square_minus_product<R>(x: R, y: R) -> R where R: Ring {
+        x * x - x * y
+    }
+
+    #[test]
+    fn groups() {
+        for &g in ALL.iter() {
+            assert_eq!(D4::Identity, g * g.inverse());
+            assert_eq!(g, power(g, 9));
+            assert_eq!(g.inverse(), power(g, -1));
+        }
+        assert_eq!(D4::Rotate270, power(D4::Rotate90, -5));
+
+        let q = Orthogonal::rotation(0.3);
+        assert_matrix_approx_eq!(Matrix::identity(), (power(q, 3) * power(q, -3)).matrix());
+        assert_eq!(Orthogonal::identity(), power(q, 0));
+
+        assert!((power(Rotation2::new(0.25), -4).angle() + 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rings_and_spaces() {
+        let m = Matrix::new(1, 2, 3, 4);
+        let n = Matrix::new(0, 1, 1, 0);
+
+        assert_eq!(m * m - m * n, square_minus_product(m, n));
+        assert_eq!(-10, square_minus_product(2, 7));
+        assert_eq!(Complex::new(0.0, 2.0), square_minus_product(Complex::new(1.0, 1.0), Complex::zero()));
+        assert_eq!(Matrix::identity(), Matrix::<i32>::one());
+
+        assert_eq!(Vector::new(0, 0), Vector::zero_vector());
+        assert_eq!(11, Vector::new(1, 2).inner(&Vector::new(3, 4)));
+        assert_eq!(Matrix::new(3, 6, 9, 12), VectorSpace::scale(&m, 3));
+        assert_eq!(5, m.inner(&n));
+    }
+}
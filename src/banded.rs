@@ -0,0 +1,188 @@
+/// An n by n matrix whose nonzero entries lie within lower diagonals below
+/// the main diagonal and upper above it, storing only that band.
+///
+/// The crate has no general dynamic matrix type, so this one stands alone,
+/// working on slices. Row i is stored as its lower + upper + 1 band
+/// entries, columns i - lower through i + upper.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct Banded {
+    size: usize,
+    lower: usize,
+    upper: usize,
+    entries: Vec<f64>
+}
+
+
+impl Banded {
+    /// The n by n zero matrix with the given bandwidths.
+    pub fn new(size: usize, lower: usize, upper: usize) -> Banded {
+        Banded {
+            size,
+            lower,
+            upper,
+            entries: vec![0.0; size * (lower + upper + 1)]
+        }
+    }
+
+    /// The tridiagonal matrix with the given diagonals, or None unless
+    /// sub and sup are one shorter than diagonal.
+    ///
+    pub fn tridiagonal(sub: &[f64], diagonal: &[f64], sup: &[f64]) -> Option<Banded> {
+        let n = diagonal.len();
+        if n == 0 || sub.len() != n - 1 || sup.len() != n - 1 {
+            return None;
+        }
+
+        let mut m = Banded::new(n, 1, 1);
+        for i in 0..n {
+            m.set(i, i, diagonal[i]);
+            if i + 1 < n {
+                m.set(i + 1, i, sub[i]);
+                m.set(i, i + 1, sup[i]);
+            }
+        }
+
+        Some(m)
+    }
+
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// The entry in row i and column j, zero outside the band.
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        self.index(i, j).map_or(0.0, |k| self.entries[k])
+    }
+
+    /// Sets the entry in row i and column j.
+    ///
+    /// # Panics
+    ///
+    /// If (i, j) lies outside the matrix or its band.
+    ///
+    pub fn set(&mut self, i: usize, j: usize, x: f64) {
+        let k = self.index(i, j).expect("entry outside the band");
+
+        self.entries[k] = x;
+    }
+
+    /// The product self * x, or None if x has the wrong length.
+    pub fn mul_vector(&self, x: &[f64]) -> Option<Vec<f64>> {
+        if x.len() != self.size {
+            return None;
+        }
+
+        Some((0..self.size).map(|i| self.columns(i).map(|j| self.get(i, j) * x[j]).sum()).collect())
+    }
+
+    /// The x solving self * x = b, or None if b has the wrong length or a
+    /// zero pivot turns up.
+    ///
+    /// Gaussian elimination without pivoting keeps every step inside the
+    /// band, so this takes O(n lower upper) time; for a tridiagonal matrix it
+    /// is the Thomas algorithm. Diagonally dominant and symmetric positive
+    /// definite matrices, such as those of 1D finite differences and cubic
+    /// splines, never need pivoting.
+    ///
+    pub fn solve(&self, b: &[f64]) -> Option<Vec<f64>> {
+        if b.len() != self.size {
+            return None;
+        }
+
+        let mut a = self.clone();
+        let mut x = b.to_vec();
+
+        for k in 0..self.size {
+            let pivot = a.get(k, k);
+            if pivot == 0.0 {
+                return None;
+            }
+
+            for i in k + 1..self.size.min(k + self.lower + 1) {
+                let factor = a.get(i, k) / pivot;
+
+                for j in a.columns(k).filter(|&j| j >= k) {
+                    let entry = a.get(i, j) - factor * a.get(k, j);
+                    a.set(i, j, entry);
+                }
+                x[i] -= factor * x[k];
+            }
+        }
+
+        for i in (0..self.size).rev() {
+            let sum: f64 = a.columns(i).filter(|&j| j > i).map(|j| a.get(i, j) * x[j]).sum();
+
+            x[i] = (x[i] - sum) / a.get(i, i);
+        }
+
+        Some(x)
+    }
+
+    // the columns of row i inside the band
+    fn columns(&self, i: usize) -> ::std::ops::Range<usize> {
+        i.saturating_sub(self.lower)..self.size.min(i + self.upper + 1)
+    }
+
+    fn index(&self, i: usize, j: usize) -> Option<usize> {
+        if i < self.size && j < self.size && j + self.lower >= i && j <= i + self.upper {
+            Some(i * (self.lower + self.upper + 1) + j + self.lower - i)
+        } else {
+            None
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use banded::Banded;
+
+    fn close(a: &[f64], b: &[f64]) -> bool {
+        a.iter().zip(b).all(|(x, y)| (x - y).abs() < 1e-12)
+    }
+
+    #[test]
+    fn storage() {
+        let mut m = Banded::new(4, 1, 2);
+
+        m.set(3, 2, 5.0);
+        m.set(0, 2, 7.0);
+        assert_eq!(5.0, m.get(3, 2));
+        assert_eq!(7.0, m.get(0, 2));
+        assert_eq!(0.0, m.get(3, 0));
+        assert_eq!(Some(vec![21.0, 0.0, 0.0, 15.0]), m.mul_vector(&[1.0, 2.0, 3.0, 4.0]));
+        assert_eq!(None, m.mul_vector(&[1.0]));
+
+        assert_eq!(None, Banded::tridiagonal(&[1.0], &[1.0, 2.0], &[]));
+    }
+
+    #[test]
+    #[should_panic]
+    fn outside_band() {
+        Banded::new(4, 1, 1).set(0, 2, 1.0);
+    }
+
+    #[test]
+    fn solving() {
+        // the second-difference matrix of -u'' = f
+        let n = 6;
+        let poisson = Banded::tridiagonal(&vec![-1.0; n - 1], &vec![2.0; n], &vec![-1.0; n - 1]).unwrap();
+        let u: Vec<f64> = (0..n).map(|i| (i as f64).sin()).collect();
+        let f = poisson.mul_vector(&u).unwrap();
+
+        assert!(close(&u, &poisson.solve(&f).unwrap()));
+
+        // a diagonally dominant pentadiagonal matrix
+        let mut m = Banded::new(5, 2, 2);
+        for i in 0..5usize {
+            for j in i.saturating_sub(2)..5.min(i + 3) {
+                m.set(i, j, if i == j { 10.0 } else { (i + 2 * j) as f64 / 7.0 });
+            }
+        }
+        let x = [1.0, -2.0, 0.5, 3.0, -1.0];
+
+        assert!(close(&x, &m.solve(&m.mul_vector(&x).unwrap()).unwrap()));
+        assert_eq!(None, Banded::tridiagonal(&[1.0], &[0.0, 1.0], &[1.0]).unwrap().solve(&[1.0, 1.0]));
+    }
+}
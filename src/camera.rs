@@ -0,0 +1,95 @@
+use Affine2;
+use Point2;
+use Rotation2;
+
+/// A 2D camera looking at position, turned by rotation and magnified by zoom.
+///
+/// Screen coordinates here are centred on the camera: the world point at
+/// position appears at the screen origin. Compose with Affine2::viewport to
+/// reach pixel coordinates.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Camera2 {
+    position: Point2<f64>,
+    rotation: Rotation2,
+    zoom: f64
+}
+
+
+impl Camera2 {
+    /// The camera, or None unless zoom is positive and finite.
+    pub fn new(position: Point2<f64>, rotation: Rotation2, zoom: f64) -> Option<Camera2> {
+        if !(zoom > 0.0 && zoom.is_finite()) {
+            return None;
+        }
+
+        Some(Camera2 {
+            position,
+            rotation,
+            zoom
+        })
+    }
+
+    pub fn position(&self) -> Point2<f64> {
+        self.position
+    }
+
+    pub fn rotation(&self) -> Rotation2 {
+        self.rotation
+    }
+
+    pub fn zoom(&self) -> f64 {
+        self.zoom
+    }
+
+    /// The transform from world to screen coordinates.
+    pub fn view(&self) -> Affine2<f64> {
+        let linear = self.rotation.inverse().matrix() * self.zoom;
+
+        Affine2::new(linear, (linear * self.position.to_vector()) * -1.0)
+    }
+
+    /// The transform from screen to world coordinates.
+    pub fn inverse_view(&self) -> Affine2<f64> {
+        Affine2::new(self.rotation.matrix() * (1.0 / self.zoom), self.position.to_vector())
+    }
+
+    pub fn world_to_screen(&self, p: Point2<f64>) -> Point2<f64> {
+        self.view() * p
+    }
+
+    pub fn screen_to_world(&self, p: Point2<f64>) -> Point2<f64> {
+        self.inverse_view() * p
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use Camera2;
+    use Point2;
+    use Rotation2;
+
+    #[test]
+    fn camera() {
+        let camera = Camera2::new(Point2::new(10.0, 5.0), Rotation2::new(FRAC_PI_2), 2.0).unwrap();
+        let p = Point2::new(11.0, 5.0);
+
+        assert_eq!(Point2::new(0.0, 0.0), camera.world_to_screen(camera.position()));
+
+        // a camera turned left sees the world turned right
+        let s = camera.world_to_screen(p);
+        assert!((s - Point2::new(0.0, -2.0)).dot(s - Point2::new(0.0, -2.0)) < 1e-24);
+        assert!((camera.screen_to_world(s) - p).dot(camera.screen_to_world(s) - p) < 1e-24);
+
+        let q = Point2::new(3.0, 4.0);
+        let d = (camera.inverse_view() * camera.view()) * q - q;
+        assert!(d.dot(d) < 1e-24);
+
+        for &zoom in [0.0, -1.0, f64::INFINITY, f64::NAN].iter() {
+            assert_eq!(None, Camera2::new(q, Rotation2::new(0.0), zoom));
+        }
+    }
+}
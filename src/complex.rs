@@ -0,0 +1,167 @@
+use std::fmt;
+use std::fmt::Display;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use num::{One, Scalar, Zero};
+
+/// Represents the complex number re + im i with parts of type T.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(C)]
+pub struct Complex<T> where T: Copy {
+    re: T,
+    im: T
+}
+
+
+// Vanilla Methods
+
+impl<T> Complex<T> where T: Copy {
+    pub const fn new(re: T, im: T) -> Complex<T> {
+        Complex {
+            re,
+            im
+        }
+    }
+
+    pub fn re(&self) -> T {
+        self.re
+    }
+
+    pub fn im(&self) -> T {
+        self.im
+    }
+
+    pub fn conj(&self) -> Complex<T> where T: Neg<Output=T> {
+        Complex::new(
+            self.re,
+            -self.im
+        )
+    }
+
+    /// The squared modulus re^2 + im^2.
+    pub fn norm_squared(&self) -> T where T: Mul<Output=T> + Add<Output=T> {
+        self.re * self.re + self.im * self.im
+    }
+}
+
+impl<T> Zero for Complex<T> where T: Copy + Zero {
+    fn zero() -> Complex<T> {
+        Complex::new(T::zero(), T::zero())
+    }
+}
+
+impl<T> One for Complex<T> where T: Copy + Zero + One {
+    fn one() -> Complex<T> {
+        Complex::new(T::one(), T::zero())
+    }
+}
+
+impl<T> Scalar for Complex<T> where T: Copy {}
+
+// Float Methods
+
+impl Complex<f64> {
+    /// The modulus |z|.
+    pub fn abs(&self) -> f64 {
+        self.re.hypot(self.im)
+    }
+
+    /// The argument, in radians within (-pi, pi].
+    pub fn arg(&self) -> f64 {
+        self.im.atan2(self.re)
+    }
+
+    /// The complex number with modulus r and argument theta.
+    pub fn from_polar(r: f64, theta: f64) -> Complex<f64> {
+        Complex::new(r * theta.cos(), r * theta.sin())
+    }
+}
+
+// Display Methods
+
+impl<T> fmt::Display for Complex<T> where T: Copy + Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}+{}i", self.re, self.im)
+    }
+}
+
+// Operator Methods
+
+/// Implementation of Complex + Complex.
+impl<T> Add<Complex<T>> for Complex<T> where T: Copy + Add<Output=T> {
+    type Output = Complex<T>;
+
+    fn add(self, rhs: Complex<T>) -> Complex<T> {
+        Complex::new(
+            self.re + rhs.re,
+            self.im + rhs.im
+        )
+    }
+}
+
+/// Implementation of Complex - Complex.
+impl<T> Sub<Complex<T>> for Complex<T> where T: Copy + Sub<Output=T> {
+    type Output = Complex<T>;
+
+    fn sub(self, rhs: Complex<T>) -> Complex<T> {
+        Complex::new(
+            self.re - rhs.re,
+            self.im - rhs.im
+        )
+    }
+}
+
+/// Implementation of Complex * Complex.
+impl<T> Mul<Complex<T>> for Complex<T> where T: Copy + Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+    type Output = Complex<T>;
+
+    fn mul(self, rhs: Complex<T>) -> Complex<T> {
+        Complex::new(
+            self.re * rhs.re - self.im * rhs.im,
+            self.re * rhs.im + self.im * rhs.re
+        )
+    }
+}
+
+/// Implementation of -Complex.
+impl<T> Neg for Complex<T> where T: Copy + Neg<Output=T> {
+    type Output = Complex<T>;
+
+    fn neg(self) -> Complex<T> {
+        Complex::new(
+            -self.re,
+            -self.im
+        )
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use Complex;
+
+    #[test]
+    fn arithmetic() {
+        let z = Complex::new(1, 2);
+        let w = Complex::new(3, -1);
+
+        assert_eq!(Complex::new(4, 1),  z + w);
+        assert_eq!(Complex::new(-2, 3), z - w);
+        assert_eq!(Complex::new(5, 5),  z * w);
+        assert_eq!(Complex::new(1, -2), z.conj());
+        assert_eq!(Complex::new(-1, -2), -z);
+        assert_eq!(5, z.norm_squared());
+        assert_eq!("1+2i", format!("{}", z));
+    }
+
+    #[test]
+    fn polar() {
+        let i = Complex::from_polar(1.0, FRAC_PI_2);
+
+        assert!((i - Complex::new(0.0, 1.0)).abs() < 1e-12);
+        assert!((FRAC_PI_2 - i.arg()).abs() < 1e-12);
+        assert_eq!(5.0, Complex::new(3.0, 4.0).abs());
+    }
+}
@@ -0,0 +1,101 @@
+use Matrix;
+
+/// The kinds of curve a x^2 + b xy + c y^2 + d x + e y + f = 0 can describe.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ConicKind {
+    Ellipse,
+
+    /// An equation like x^2 + y^2 + 1 = 0, non-degenerate but with no real points.
+    ImaginaryEllipse,
+
+    Parabola,
+    Hyperbola,
+
+    /// A pair of lines, a single line, a point, or nothing.
+    Degenerate
+}
+
+/// The symmetric matrix of the quadratic part: [[a, b/2], [b/2, c]].
+pub fn quadratic_matrix(a: f64, b: f64, c: f64) -> Matrix<f64> {
+    Matrix::new(a, b / 2.0, b / 2.0, c)
+}
+
+/// Classifies the conic a x^2 + b xy + c y^2 + d x + e y + f = 0.
+///
+/// Degeneracy is decided by the determinant of the bordered 3x3 matrix
+/// [[a, b/2, d/2], [b/2, c, e/2], [d/2, e/2, f]], and the kind of curve by the
+/// determinant of the quadratic part.
+///
+pub fn classify(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> ConicKind {
+    let q = quadratic_matrix(a, b, c);
+    let (h, g) = (d / 2.0, e / 2.0);
+
+    // cofactor expansion of the bordered matrix along its last row
+    let bordered = h * (q.b * g - q.d * h) - g * (q.a * g - q.c * h) + f * q.determinant();
+
+    if bordered == 0.0 {
+        return ConicKind::Degenerate;
+    }
+
+    let delta = q.determinant();
+
+    if delta > 0.0 {
+        if q.trace() * bordered < 0.0 { ConicKind::Ellipse } else { ConicKind::ImaginaryEllipse }
+    } else if delta == 0.0 {
+        ConicKind::Parabola
+    } else {
+        ConicKind::Hyperbola
+    }
+}
+
+/// The principal axes of the quadratic part a x^2 + b xy + c y^2.
+///
+/// Returns a rotation matrix whose columns are the axis directions, with the
+/// eigenvalue of the quadratic form along each; in the rotated coordinates the
+/// xy term vanishes. NaN coefficients give NaN entries.
+///
+pub fn principal_axes(a: f64, b: f64, c: f64) -> (Matrix<f64>, (f64, f64)) {
+    // symmetric, so the symmetric solver applies
+    let (_, eigenvalues, axes) = quadratic_matrix(a, b, c).symmetric_eigen();
+
+    (axes, eigenvalues)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use conics::{classify, principal_axes, quadratic_matrix, ConicKind};
+
+    #[test]
+    fn classification() {
+        assert_eq!(ConicKind::Ellipse,          classify(1.0, 0.0, 1.0, 0.0, 0.0, -1.0));
+        assert_eq!(ConicKind::Ellipse,          classify(2.0, 1.0, 3.0, -4.0, 1.0, -5.0));
+        assert_eq!(ConicKind::ImaginaryEllipse, classify(1.0, 0.0, 1.0, 0.0, 0.0, 1.0));
+        assert_eq!(ConicKind::Parabola,         classify(1.0, 0.0, 0.0, 0.0, -1.0, 0.0));
+        assert_eq!(ConicKind::Parabola,         classify(1.0, 2.0, 1.0, 1.0, 0.0, 0.0));
+        assert_eq!(ConicKind::Hyperbola,        classify(1.0, 0.0, -1.0, 0.0, 0.0, -1.0));
+        assert_eq!(ConicKind::Hyperbola,        classify(0.0, 1.0, 0.0, 0.0, 0.0, -1.0));
+
+        // x^2 - y^2 = 0 is a pair of lines; x^2 + y^2 = 0 a point
+        assert_eq!(ConicKind::Degenerate,       classify(1.0, 0.0, -1.0, 0.0, 0.0, 0.0));
+        assert_eq!(ConicKind::Degenerate,       classify(1.0, 0.0, 1.0, 0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn axes() {
+        // xy = 1 has axes along the diagonals
+        let (r, (l1, l2)) = principal_axes(0.0, 1.0, 0.0);
+        let diagonal = r.left();
+
+        assert!((0.5 - l1).abs() < 1e-12 && (-0.5 - l2).abs() < 1e-12);
+        assert!((diagonal.x.abs() - diagonal.y.abs()).abs() < 1e-12);
+        assert!((1.0 - r.determinant()).abs() < 1e-12);
+
+        // in the rotated frame the form is diagonal
+        let q = r.transpose() * quadratic_matrix(0.0, 1.0, 0.0) * r;
+        assert!(q.right().x.abs() < 1e-12 && q.left().y.abs() < 1e-12);
+
+        let (r, (l1, _)) = principal_axes(f64::NAN, 0.0, 1.0);
+        assert!(r.a.is_nan() && l1.is_nan());
+    }
+}
@@ -0,0 +1,108 @@
+use modular::ZnMatrix;
+use Matrix;
+use Vector;
+
+const ALPHABET_SIZE: i64 = 26;
+
+/// Appended to odd-length messages to complete the final pair of letters.
+const PADDING: u8 = b'X';
+
+/// A Hill cipher key: a 2x2 matrix invertible modulo 26.
+///
+/// Messages are read as pairs of letters A = 0, ..., Z = 25, each pair
+/// encrypted by multiplying it as a column vector by the key.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct HillKey {
+    matrix: ZnMatrix
+}
+
+
+impl HillKey {
+    /// Returns None unless key is invertible modulo 26, i.e. its determinant is
+    /// odd and not a multiple of 13.
+    pub fn new(key: Matrix<i64>) -> Option<HillKey> {
+        let matrix = ZnMatrix::new(key, ALPHABET_SIZE)?;
+
+        matrix.inverse().map(|_| HillKey { matrix })
+    }
+
+    pub fn matrix(&self) -> Matrix<i64> {
+        self.matrix.matrix()
+    }
+
+    /// The key that decrypts what this one encrypts.
+    pub fn inverse(&self) -> HillKey {
+        HillKey {
+            matrix: self.matrix.inverse().unwrap()
+        }
+    }
+
+    /// Encrypts the ASCII letters of plaintext, ignoring case and skipping
+    /// everything else, padding with X to an even length.
+    ///
+    /// The result is in upper case.
+    ///
+    pub fn encrypt(&self, plaintext: &[u8]) -> Vec<u8> {
+        let mut letters: Vec<i64> = plaintext.iter()
+            .filter(|b| b.is_ascii_alphabetic())
+            .map(|b| (b.to_ascii_uppercase() - b'A') as i64)
+            .collect();
+
+        if letters.len() % 2 == 1 {
+            letters.push((PADDING - b'A') as i64);
+        }
+
+        let key = self.matrix();
+
+        letters.chunks(2).flat_map(|pair| {
+            let v = key * Vector::new(pair[0], pair[1]);
+
+            vec![to_letter(v.x), to_letter(v.y)]
+        }).collect()
+    }
+
+    /// Decrypts ciphertext, as encryption with the inverse key.
+    ///
+    /// Any padding added during encryption is left in place.
+    ///
+    pub fn decrypt(&self, ciphertext: &[u8]) -> Vec<u8> {
+        self.inverse().encrypt(ciphertext)
+    }
+}
+
+fn to_letter(n: i64) -> u8 {
+    b'A' + n.rem_euclid(ALPHABET_SIZE) as u8
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crypto::hill::HillKey;
+    use Matrix;
+
+    #[test]
+    fn keys() {
+        assert!(HillKey::new(Matrix::new(3, 3, 2, 5)).is_some());
+
+        // determinants 2 and 13 share factors with 26
+        assert!(HillKey::new(Matrix::new(2, 0, 0, 1)).is_none());
+        assert!(HillKey::new(Matrix::new(13, 0, 0, 1)).is_none());
+
+        let key = HillKey::new(Matrix::new(3, 3, 2, 5)).unwrap();
+        assert_eq!(Matrix::new(15, 17, 20, 9), key.inverse().matrix());
+        assert_eq!(key, key.inverse().inverse());
+    }
+
+    #[test]
+    fn round_trip() {
+        let key = HillKey::new(Matrix::new(3, 3, 2, 5)).unwrap();
+
+        // HE -> (7, 4) -> (33, 34) = (7, 8) -> HI
+        assert_eq!(b"HIAT".to_vec(), key.encrypt(b"help"));
+
+        let ciphertext = key.encrypt(b"Attack at dawn!");
+        assert_eq!(b"ATTACKATDAWN".to_vec(), key.decrypt(&ciphertext));
+        assert_eq!(b"ODDX".to_vec(), key.decrypt(&key.encrypt(b"odd")));
+    }
+}
@@ -0,0 +1,133 @@
+use std::ops::{Add, Mul};
+
+use num::Zero;
+use Matrix;
+use Vector;
+
+/// Represents the diagonal matrix diag(x, y), such as a scale transform.
+///
+/// Products with full matrices scale rows or columns, and inversion is two
+/// divisions.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Diagonal2<T> where T: Copy {
+    x: T,
+    y: T
+}
+
+
+// Vanilla Methods
+
+impl<T> Diagonal2<T> where T: Copy {
+    pub fn new(x: T, y: T) -> Diagonal2<T> {
+        Diagonal2 {
+            x,
+            y
+        }
+    }
+
+    /// m as a Diagonal2, or None unless both off-diagonal entries are zero.
+    pub fn from_matrix(m: &Matrix<T>) -> Option<Diagonal2<T>> where T: Zero + PartialEq {
+        if m.b == T::zero() && m.c == T::zero() {
+            Some(Diagonal2::new(m.a, m.d))
+        } else {
+            None
+        }
+    }
+
+    /// The diagonal entries as a vector.
+    pub fn diagonal(&self) -> Vector<T> {
+        Vector::new(self.x, self.y)
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> where T: Zero {
+        Matrix::new(self.x, T::zero(), T::zero(), self.y)
+    }
+
+    pub fn determinant(&self) -> T where T: Mul<Output=T> {
+        self.x * self.y
+    }
+
+    pub fn trace(&self) -> T where T: Add<Output=T> {
+        self.x + self.y
+    }
+}
+
+// Float Methods
+
+impl Diagonal2<f64> {
+    /// The inverse diag(1 / x, 1 / y), or None if either entry is zero.
+    pub fn inverse(&self) -> Option<Diagonal2<f64>> {
+        if self.x == 0.0 || self.y == 0.0 {
+            return None;
+        }
+
+        Some(Diagonal2::new(1.0 / self.x, 1.0 / self.y))
+    }
+}
+
+// Operator Methods
+
+/// Implementation of Diagonal2 * Diagonal2.
+impl<T> Mul<Diagonal2<T>> for Diagonal2<T> where T: Copy + Mul<Output=T> {
+    type Output = Diagonal2<T>;
+
+    fn mul(self, rhs: Diagonal2<T>) -> Diagonal2<T> {
+        Diagonal2::new(self.x * rhs.x, self.y * rhs.y)
+    }
+}
+
+/// Implementation of Diagonal2 * Vector.
+impl<T> Mul<Vector<T>> for Diagonal2<T> where T: Copy + Mul<Output=T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: Vector<T>) -> Vector<T> {
+        Vector::new(self.x * rhs.x, self.y * rhs.y)
+    }
+}
+
+/// Implementation of Diagonal2 * Matrix, scaling its rows.
+impl<T> Mul<Matrix<T>> for Diagonal2<T> where T: Copy + Mul<Output=T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Matrix<T>) -> Matrix<T> {
+        Matrix::new(self.x * rhs.a, self.x * rhs.b, self.y * rhs.c, self.y * rhs.d)
+    }
+}
+
+/// Implementation of Matrix * Diagonal2, scaling its columns.
+impl<T> Mul<Diagonal2<T>> for Matrix<T> where T: Copy + Mul<Output=T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Diagonal2<T>) -> Matrix<T> {
+        Matrix::new(self.a * rhs.x, self.b * rhs.y, self.c * rhs.x, self.d * rhs.y)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use Diagonal2;
+    use Matrix;
+    use Vector;
+
+    #[test]
+    fn diagonal() {
+        let s = Diagonal2::new(2, 3);
+        let m = Matrix::new(1, 2, 3, 4);
+
+        assert_eq!(Matrix::new(2, 0, 0, 3), s.to_matrix());
+        assert_eq!(Some(s), Diagonal2::from_matrix(&s.to_matrix()));
+        assert_eq!(None, Diagonal2::from_matrix(&m));
+        assert_eq!(6, s.determinant());
+        assert_eq!(Vector::new(2, 6), s * Vector::new(1, 2));
+        assert_eq!(s.to_matrix() * m, s * m);
+        assert_eq!(m * s.to_matrix(), m * s);
+        assert_eq!(Diagonal2::new(4, 9), s * s);
+
+        let f = Diagonal2::new(4.0, -0.5);
+        assert_eq!(Some(Diagonal2::new(0.25, -2.0)), f.inverse());
+        assert_eq!(Matrix::new(1.0, 0.0, 0.0, 1.0), (f * f.inverse().unwrap()).to_matrix());
+        assert_eq!(None, Diagonal2::new(1.0, 0.0).inverse());
+    }
+}
@@ -0,0 +1,93 @@
+use Matrix;
+use Vector;
+
+/// The behaviour of the linear system x' = A x near its fixed point at the origin.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FixedPoint {
+    /// Real eigenvalues of opposite sign.
+    Saddle,
+
+    /// Real negative eigenvalues, including repeated ones.
+    StableNode,
+    UnstableNode,
+
+    /// Complex eigenvalues with negative real part.
+    StableSpiral,
+    UnstableSpiral,
+
+    /// Purely imaginary eigenvalues: closed orbits.
+    Center,
+
+    /// A zero eigenvalue, so the fixed point is not isolated.
+    Degenerate
+}
+
+/// Classifies the origin of x' = a x by the trace and determinant of a.
+pub fn classify(a: Matrix<f64>) -> FixedPoint {
+    let (tr, det) = (a.trace(), a.determinant());
+
+    if det < 0.0 {
+        FixedPoint::Saddle
+    } else if det == 0.0 {
+        FixedPoint::Degenerate
+    } else if tr == 0.0 {
+        FixedPoint::Center
+    } else if tr * tr >= 4.0 * det {
+        if tr < 0.0 { FixedPoint::StableNode } else { FixedPoint::UnstableNode }
+    } else if tr < 0.0 {
+        FixedPoint::StableSpiral
+    } else {
+        FixedPoint::UnstableSpiral
+    }
+}
+
+/// The trajectory of x' = a x through x0 at time 0, as the map t -> e^(a t) x0.
+pub fn solve_ivp(a: Matrix<f64>, x0: Vector<f64>) -> impl Fn(f64) -> Vector<f64> {
+    move |t| (a * t).exp() * x0
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use dynamics::{classify, solve_ivp, FixedPoint};
+    use Matrix;
+    use Vector;
+
+    #[test]
+    fn classification() {
+        assert_eq!(FixedPoint::Saddle,         classify(Matrix::new(1.0, 0.0, 0.0, -1.0)));
+        assert_eq!(FixedPoint::StableNode,     classify(Matrix::new(-1.0, 0.0, 0.0, -2.0)));
+        assert_eq!(FixedPoint::StableNode,     classify(Matrix::new(-1.0, 1.0, 0.0, -1.0)));
+        assert_eq!(FixedPoint::UnstableNode,   classify(Matrix::new(2.0, 1.0, 0.0, 3.0)));
+        assert_eq!(FixedPoint::StableSpiral,   classify(Matrix::new(-0.1, -1.0, 1.0, -0.1)));
+        assert_eq!(FixedPoint::UnstableSpiral, classify(Matrix::new(0.1, -1.0, 1.0, 0.1)));
+        assert_eq!(FixedPoint::Center,         classify(Matrix::new(0.0, -1.0, 1.0, 0.0)));
+        assert_eq!(FixedPoint::Degenerate,     classify(Matrix::new(1.0, 2.0, 2.0, 4.0)));
+    }
+
+    #[test]
+    fn trajectories() {
+        let x0 = Vector::new(1.0, 0.0);
+
+        let circle = solve_ivp(Matrix::new(0.0, -1.0, 1.0, 0.0), x0);
+        assert_eq!(x0, circle(0.0));
+        assert!((Vector::new(0.0, 1.0) - circle(PI / 2.0)).length() < 1e-12);
+        assert!((x0 - circle(2.0 * PI)).length() < 1e-12);
+
+        let decay = solve_ivp(Matrix::new(-1.0, 0.0, 0.0, -2.0), Vector::new(1.0, 1.0));
+        assert!((Vector::new((-3.0f64).exp(), (-6.0f64).exp()) - decay(3.0)).length() < 1e-12);
+
+        // x' = y, y' = 0 moves at constant velocity
+        let drift = solve_ivp(Matrix::new(0.0, 1.0, 0.0, 0.0), Vector::new(0.0, 2.0));
+        assert_eq!(Vector::new(10.0, 2.0), drift(5.0));
+
+        // the solution satisfies the differential equation
+        let a = Matrix::new(1.0, 2.0, -3.0, 0.5);
+        let x = solve_ivp(a, Vector::new(1.0, -1.0));
+        let h = 1e-5;
+        let derivative = (x(0.7 + h) - x(0.7 - h)) * (1.0 / (2.0 * h));
+        assert!((a * x(0.7) - derivative).length() < 1e-6);
+    }
+}
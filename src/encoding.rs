@@ -0,0 +1,130 @@
+use num::LeBytes;
+use Affine2;
+use Matrix;
+use Vector;
+
+/// The first byte of every encoding.
+pub const MAGIC: u8 = 0xb2;
+
+/// The format version written by encode; decode reads this and earlier.
+pub const VERSION: u8 = 1;
+
+/// Element types with a tag in the encoding header.
+pub trait Element: LeBytes + Copy {
+    const TAG: u8;
+}
+
+macro_rules! impl_element {
+    ($($t:ty, $tag:expr);*) => {
+        $(
+            impl Element for $t {
+                const TAG: u8 = $tag;
+            }
+        )*
+    }
+}
+
+impl_element!(i8, 0x01; i16, 0x02; i32, 0x03; i64, 0x04; i128, 0x05;
+              u8, 0x11; u16, 0x12; u32, 0x13; u64, 0x14; u128, 0x15;
+              f32, 0x21; f64, 0x22);
+
+/// Values with a self-describing, versioned byte encoding.
+///
+/// An encoding is four header bytes, MAGIC, VERSION, a shape tag (1 for
+/// Matrix, 2 for Vector, 3 for Affine2) and the element's Element::TAG,
+/// followed by the to_bytes payload. Decoding checks the whole header, so a
+/// Matrix<f32> is never mistaken for a Vector<f64> of the same length.
+///
+pub trait Encoded: Sized {
+    fn encode(&self) -> Vec<u8>;
+
+    /// The value of an encoding, or None if the header does not match this
+    /// type or the payload has the wrong length.
+    ///
+    fn decode(bytes: &[u8]) -> Option<Self>;
+}
+
+impl<T> Encoded for Matrix<T> where T: Element {
+    fn encode(&self) -> Vec<u8> {
+        with_header(1, T::TAG, self.to_bytes())
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Matrix<T>> {
+        Matrix::from_bytes(payload(bytes, 1, T::TAG)?)
+    }
+}
+
+impl<T> Encoded for Vector<T> where T: Element {
+    fn encode(&self) -> Vec<u8> {
+        with_header(2, T::TAG, self.to_bytes())
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Vector<T>> {
+        Vector::from_bytes(payload(bytes, 2, T::TAG)?)
+    }
+}
+
+impl<T> Encoded for Affine2<T> where T: Element {
+    fn encode(&self) -> Vec<u8> {
+        with_header(3, T::TAG, self.to_bytes())
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Affine2<T>> {
+        Affine2::from_bytes(payload(bytes, 3, T::TAG)?)
+    }
+}
+
+fn with_header(shape: u8, element: u8, payload: Vec<u8>) -> Vec<u8> {
+    let mut bytes = vec![MAGIC, VERSION, shape, element];
+    bytes.extend(payload);
+
+    bytes
+}
+
+fn payload(bytes: &[u8], shape: u8, element: u8) -> Option<&[u8]> {
+    match *bytes {
+        [magic, version, s, e, ref payload @ ..]
+            if magic == MAGIC && (1..=VERSION).contains(&version) && s == shape && e == element => Some(payload),
+        _ => None
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use encoding::{Encoded, MAGIC, VERSION};
+    use Affine2;
+    use Matrix;
+    use Vector;
+
+    #[test]
+    fn round_trips() {
+        let m = Matrix::new(1u8, 2, 3, 4);
+        assert_eq!(vec![MAGIC, VERSION, 1, 0x11, 1, 2, 3, 4], m.encode());
+        assert_eq!(Some(m), Matrix::decode(&m.encode()));
+
+        let v = Vector::new(-1.5f64, 2.0);
+        assert_eq!(Some(v), Vector::decode(&v.encode()));
+
+        let a = Affine2::new(Matrix::new(1i64, 2, 3, 4), Vector::new(5, 6));
+        assert_eq!(52, a.encode().len());
+        assert_eq!(Some(a), Affine2::decode(&a.encode()));
+    }
+
+    #[test]
+    fn rejects() {
+        let bytes = Matrix::new(1.0f32, 2.0, 3.0, 4.0).encode();
+
+        // same length, different shape or element type
+        assert_eq!(None, Vector::<f64>::decode(&bytes));
+        assert_eq!(None, Matrix::<i32>::decode(&bytes));
+
+        for i in 0..2 {
+            let mut corrupt = bytes.clone();
+            corrupt[i] = corrupt[i].wrapping_add(1);
+            assert_eq!(None, Matrix::<f32>::decode(&corrupt));
+        }
+        assert_eq!(None, Matrix::<f32>::decode(&bytes[..bytes.len() - 1]));
+        assert_eq!(None, Matrix::<f32>::decode(&[]));
+    }
+}
@@ -0,0 +1,213 @@
+use std::ops::{Add, Mul, Sub};
+
+use Matrix;
+
+/// A matrix expression whose entries are computed on demand.
+///
+/// Nodes are built by the operators on Lazy and only evaluated by
+/// Lazy::eval, which computes each entry of the result in one pass over the
+/// whole expression, so a * b + c * d never stores a * b or c * d. Only the
+/// operands of a product are evaluated early, once, as each of its entries
+/// reads them twice.
+///
+/// For Copy entries such as f64 an optimised build already fuses the eager
+/// path, and benches/expr.rs times the two the same, chains of products
+/// included; any savings come with costlier entry types.
+///
+pub trait MatrixExpr: Copy {
+    type Entry: Copy;
+
+    /// The entry in row i and column j, both 0 or 1.
+    fn entry(&self, i: usize, j: usize) -> Self::Entry;
+}
+
+/// An expression, wrapped so the operators can build larger ones.
+#[derive(Clone, Copy, Debug)]
+pub struct Lazy<E> {
+    expr: E
+}
+
+/// A borrowed matrix, the leaves of an expression.
+#[derive(Clone, Copy, Debug)]
+pub struct Leaf<'a, T> where T: Copy + 'a {
+    matrix: &'a Matrix<T>
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Sum<A, B> {
+    lhs: A,
+    rhs: B
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Difference<A, B> {
+    lhs: A,
+    rhs: B
+}
+
+/// A product, whose operands are evaluated once when it is built, as each
+/// entry reads a row of lhs and a column of rhs; a chain of products then
+/// costs time linear in its length, not doubling with every factor.
+///
+#[derive(Clone, Copy, Debug)]
+pub struct Product<T> where T: Copy {
+    lhs: Matrix<T>,
+    rhs: Matrix<T>
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct Scaled<E, T> {
+    expr: E,
+    factor: T
+}
+
+/// m as the leaf of a lazy expression.
+pub fn lazy<'a, T>(m: &'a Matrix<T>) -> Lazy<Leaf<'a, T>> where T: Copy {
+    Lazy {
+        expr: Leaf {
+            matrix: m
+        }
+    }
+}
+
+
+impl<E> Lazy<E> where E: MatrixExpr {
+    /// The expression with every entry multiplied by factor.
+    pub fn scale(self, factor: E::Entry) -> Lazy<Scaled<E, E::Entry>> {
+        Lazy {
+            expr: Scaled {
+                expr: self.expr,
+                factor
+            }
+        }
+    }
+
+    /// The value of the expression, computed entry by entry.
+    pub fn eval(&self) -> Matrix<E::Entry> {
+        Matrix::new(self.expr.entry(0, 0), self.expr.entry(0, 1), self.expr.entry(1, 0), self.expr.entry(1, 1))
+    }
+}
+
+impl<'a, T> MatrixExpr for Leaf<'a, T> where T: Copy {
+    type Entry = T;
+
+    fn entry(&self, i: usize, j: usize) -> T {
+        match (i, j) {
+            (0, 0) => self.matrix.a,
+            (0, _) => self.matrix.b,
+            (_, 0) => self.matrix.c,
+            _      => self.matrix.d
+        }
+    }
+}
+
+impl<A, B> MatrixExpr for Sum<A, B> where A: MatrixExpr, B: MatrixExpr<Entry=A::Entry>, A::Entry: Add<Output=A::Entry> {
+    type Entry = A::Entry;
+
+    fn entry(&self, i: usize, j: usize) -> A::Entry {
+        self.lhs.entry(i, j) + self.rhs.entry(i, j)
+    }
+}
+
+impl<A, B> MatrixExpr for Difference<A, B> where A: MatrixExpr, B: MatrixExpr<Entry=A::Entry>, A::Entry: Sub<Output=A::Entry> {
+    type Entry = A::Entry;
+
+    fn entry(&self, i: usize, j: usize) -> A::Entry {
+        self.lhs.entry(i, j) - self.rhs.entry(i, j)
+    }
+}
+
+impl<T> MatrixExpr for Product<T> where T: Copy + Mul<Output=T> + Add<Output=T> {
+    type Entry = T;
+
+    fn entry(&self, i: usize, j: usize) -> T {
+        let (lhs, rhs) = (Leaf { matrix: &self.lhs }, Leaf { matrix: &self.rhs });
+
+        lhs.entry(i, 0) * rhs.entry(0, j) + lhs.entry(i, 1) * rhs.entry(1, j)
+    }
+}
+
+impl<E, T> MatrixExpr for Scaled<E, T> where E: MatrixExpr<Entry=T>, T: Copy + Mul<Output=T> {
+    type Entry = T;
+
+    fn entry(&self, i: usize, j: usize) -> T {
+        self.factor * self.expr.entry(i, j)
+    }
+}
+
+// Operator Methods
+
+/// Implementation of Lazy + Lazy.
+impl<A, B> Add<Lazy<B>> for Lazy<A> where A: MatrixExpr, B: MatrixExpr<Entry=A::Entry> {
+    type Output = Lazy<Sum<A, B>>;
+
+    fn add(self, rhs: Lazy<B>) -> Lazy<Sum<A, B>> {
+        Lazy {
+            expr: Sum {
+                lhs: self.expr,
+                rhs: rhs.expr
+            }
+        }
+    }
+}
+
+/// Implementation of Lazy - Lazy.
+impl<A, B> Sub<Lazy<B>> for Lazy<A> where A: MatrixExpr, B: MatrixExpr<Entry=A::Entry> {
+    type Output = Lazy<Difference<A, B>>;
+
+    fn sub(self, rhs: Lazy<B>) -> Lazy<Difference<A, B>> {
+        Lazy {
+            expr: Difference {
+                lhs: self.expr,
+                rhs: rhs.expr
+            }
+        }
+    }
+}
+
+/// Implementation of Lazy * Lazy, evaluating both operands.
+impl<A, B> Mul<Lazy<B>> for Lazy<A> where A: MatrixExpr, B: MatrixExpr<Entry=A::Entry> {
+    type Output = Lazy<Product<A::Entry>>;
+
+    fn mul(self, rhs: Lazy<B>) -> Lazy<Product<A::Entry>> {
+        Lazy {
+            expr: Product {
+                lhs: self.eval(),
+                rhs: rhs.eval()
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use expr::lazy;
+    use Matrix;
+
+    #[test]
+    fn fused() {
+        let a = Matrix::new(1, 2, 3, 4);
+        let b = Matrix::new(0, 1, -1, 2);
+        let c = Matrix::new(5, -3, 2, 1);
+        let d = Matrix::new(2, 0, 0, 2);
+
+        assert_eq!(a * b + c * d, (lazy(&a) * lazy(&b) + lazy(&c) * lazy(&d)).eval());
+        assert_eq!(a * b - c, (lazy(&a) * lazy(&b) - lazy(&c)).eval());
+        assert_eq!(a * b * c, (lazy(&a) * lazy(&b) * lazy(&c)).eval());
+        assert_eq!((a + b).scale(3) * d, ((lazy(&a) + lazy(&b)).scale(3) * lazy(&d)).eval());
+        assert_eq!(a, lazy(&a).eval());
+    }
+
+    #[test]
+    fn long_chains() {
+        let f = Matrix::new(1i64, 1, 1, 0);
+        let mut chain = lazy(&f) * lazy(&f);
+
+        // 60 factors, which would take 2^60 steps were operands not evaluated
+        for _ in 0..58 {
+            chain = chain * lazy(&f);
+        }
+        assert_eq!(Matrix::new(2504730781961, 1548008755920, 1548008755920, 956722026041), chain.eval());
+    }
+}
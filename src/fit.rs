@@ -0,0 +1,210 @@
+use statistics::mean;
+use Isometry2;
+use Matrix;
+use Rotation2;
+use Vector;
+
+/// A straight line y = slope * x + intercept fitted to data.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LineFit {
+    pub slope: f64,
+    pub intercept: f64,
+
+    /// Estimated covariance of (slope, intercept).
+    pub covariance: Matrix<f64>,
+
+    /// Weighted sum of squared residuals.
+    pub residual_sum_of_squares: f64,
+
+    /// Coefficient of determination: the fraction of the variance in y explained by the line.
+    ///
+    /// NaN when all the ys are equal, as there is then no variance to explain.
+    ///
+    pub r_squared: f64
+}
+
+/// The least-squares line through the points (xs[i], ys[i]).
+///
+/// Solves the normal equations X^t X beta = X^t y, where the rows of X are
+/// [x 1]. Returns None if xs and ys differ in length or the xs do not
+/// contain two distinct values. The covariance needs at least three points,
+/// and is infinite or NaN otherwise.
+///
+pub fn linear_least_squares(xs: &[f64], ys: &[f64]) -> Option<LineFit> {
+    let weights = vec![1.0; xs.len()];
+
+    weighted_least_squares(xs, ys, &weights)
+}
+
+/// The line minimising the sum of weights[i] * (ys[i] - (slope * xs[i] + intercept))^2.
+///
+/// As linear_least_squares, solving X^t W X beta = X^t W y, and None also
+/// if weights differs in length or holds a negative or non-finite weight.
+/// A point of zero weight is dropped entirely, and does not count towards
+/// the three points the covariance needs.
+///
+pub fn weighted_least_squares(xs: &[f64], ys: &[f64], weights: &[f64]) -> Option<LineFit> {
+    if xs.len() != ys.len() || xs.len() != weights.len() || weights.iter().any(|&w| !(w >= 0.0 && w.is_finite())) {
+        return None;
+    }
+
+    let points = || xs.iter().zip(ys).zip(weights).map(|((&x, &y), &w)| (x, y, w));
+    let n = points().filter(|&(_, _, w)| w > 0.0).count();
+
+    let (mut sw, mut sx, mut sxx, mut sy, mut sxy) = (0.0, 0.0, 0.0, 0.0, 0.0);
+    for (x, y, w) in points() {
+        sw += w;
+        sx += w * x;
+        sxx += w * x * x;
+        sy += w * y;
+        sxy += w * x * y;
+    }
+
+    let normal = Matrix::new(sxx, sx, sx, sw);
+    let beta = normal.solve(Vector::new(sxy, sy))?;
+    let (slope, intercept) = (beta.x, beta.y);
+
+    let y_mean = sy / sw;
+    let (mut rss, mut tss) = (0.0, 0.0);
+    for (x, y, w) in points() {
+        let residual = y - (slope * x + intercept);
+
+        rss += w * residual * residual;
+        tss += w * (y - y_mean) * (y - y_mean);
+    }
+    let variance = rss / (n as f64 - 2.0);
+
+    Some(LineFit {
+        slope,
+        intercept,
+        covariance: normal.inverse()? * variance,
+        residual_sum_of_squares: rss,
+        r_squared: 1.0 - rss / tss
+    })
+}
+
+/// The rotation about the origin best mapping each src[i] onto dst[i], in the
+/// least-squares sense.
+///
+/// This is the Kabsch algorithm: the rotation comes from the SVD of the
+/// cross-covariance of the two point sets, with any reflection removed.
+///
+pub fn best_rotation(src: &[Vector<f64>], dst: &[Vector<f64>]) -> Rotation2 {
+    let h = src.iter().zip(dst).fold(Matrix::new(0.0, 0.0, 0.0, 0.0), |acc, (&p, &q)| {
+        acc + Matrix::new(p.x * q.x, p.x * q.y, p.y * q.x, p.y * q.y)
+    });
+
+    let (u, _, v) = h.svd();
+    let d = (v * u.transpose()).determinant().signum();
+
+    Rotation2::from_matrix(&(v * Matrix::new(1.0, 0.0, 0.0, d) * u.transpose()))
+}
+
+/// The rigid motion best mapping each src[i] onto dst[i], in the least-squares sense.
+pub fn best_rigid(src: &[Vector<f64>], dst: &[Vector<f64>]) -> Isometry2 {
+    let (src_mean, dst_mean) = (mean(src), mean(dst));
+    let centered_src: Vec<Vector<f64>> = src.iter().map(|&p| p - src_mean).collect();
+    let centered_dst: Vec<Vector<f64>> = dst.iter().map(|&q| q - dst_mean).collect();
+
+    let rotation = best_rotation(&centered_src, &centered_dst);
+
+    Isometry2::new(rotation, dst_mean - rotation * src_mean)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use fit::{best_rigid, best_rotation, linear_least_squares, weighted_least_squares};
+    use Isometry2;
+    use Matrix;
+    use Point2;
+    use Rotation2;
+    use Vector;
+
+    fn shape() -> Vec<Vector<f64>> {
+        vec![
+            Vector::new(0.0, 0.0),
+            Vector::new(3.0, 0.0),
+            Vector::new(3.0, 1.0),
+            Vector::new(1.0, 2.0)
+        ]
+    }
+
+    #[test]
+    fn rotations() {
+        let turn = Rotation2::new(2.5);
+        let src = shape();
+        let dst: Vec<Vector<f64>> = src.iter().map(|&p| turn * p).collect();
+
+        assert!((2.5 - best_rotation(&src, &dst).angle()).abs() < 1e-12);
+
+        // a mirror image is matched by some rotation, never a reflection
+        let mirrored: Vec<Vector<f64>> = src.iter().map(|p| Vector::new(p.x, -p.y)).collect();
+        let r = best_rotation(&src, &mirrored).matrix();
+        assert!((1.0 - r.determinant()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rigid_motions() {
+        let motion = Isometry2::new(Rotation2::new(-1.0), Vector::new(4.0, 7.0));
+        let src = shape();
+        let dst: Vec<Vector<f64>> = src.iter().map(|&p| (motion * Point2::from_vector(p)).to_vector()).collect();
+
+        let fitted = best_rigid(&src, &dst);
+
+        assert!((-1.0 - fitted.rotation().angle()).abs() < 1e-12);
+        assert!(fitted.translation().distance(Vector::new(4.0, 7.0)) < 1e-12);
+    }
+
+    #[test]
+    fn lines() {
+        let exact = linear_least_squares(&[0.0, 1.0, 2.0, 3.0], &[1.0, 3.0, 5.0, 7.0]).unwrap();
+
+        assert_eq!(2.0, exact.slope);
+        assert_eq!(1.0, exact.intercept);
+        assert_eq!(0.0, exact.covariance.frobenius_norm());
+        assert_eq!(0.0, exact.residual_sum_of_squares);
+        assert_eq!(1.0, exact.r_squared);
+
+        let noisy = linear_least_squares(&[0.0, 1.0, 2.0, 3.0], &[0.0, 2.0, 1.0, 3.0]).unwrap();
+        assert!((0.8 - noisy.slope).abs() < 1e-12);
+        assert!((0.3 - noisy.intercept).abs() < 1e-12);
+
+        // residuals 0.3^2 + 0.9^2 + 0.9^2 + 0.3^2 = 1.8 over 2 degrees of freedom,
+        // times (X^t X)^-1 = [[0.2, -0.3], [-0.3, 0.7]]
+        assert!((noisy.covariance - Matrix::new(0.18, -0.27, -0.27, 0.63)).frobenius_norm() < 1e-12);
+        assert!((1.8 - noisy.residual_sum_of_squares).abs() < 1e-12);
+        assert!((0.64 - noisy.r_squared).abs() < 1e-12);
+
+        assert_eq!(None, linear_least_squares(&[1.0, 1.0], &[0.0, 2.0]));
+        assert!(linear_least_squares(&[0.0, 1.0, 2.0], &[4.0, 4.0, 4.0]).unwrap().r_squared.is_nan());
+        assert_eq!(None, linear_least_squares(&[0.0, 1.0, 2.0, 3.0], &[1.0, 3.0]));
+    }
+
+    #[test]
+    fn weighted_lines() {
+        let xs = [0.0, 1.0, 2.0, 3.0];
+        let ys = [0.0, 2.0, 1.0, 3.0];
+
+        assert_eq!(linear_least_squares(&xs, &ys), weighted_least_squares(&xs, &ys, &[1.0; 4]));
+
+        // a zero weight drops the point entirely
+        let dropped = weighted_least_squares(&xs, &ys, &[1.0, 1.0, 0.0, 1.0]).unwrap();
+        let without = linear_least_squares(&[0.0, 1.0, 3.0], &[0.0, 2.0, 3.0]).unwrap();
+        assert!((without.slope - dropped.slope).abs() < 1e-12);
+        assert!((without.intercept - dropped.intercept).abs() < 1e-12);
+        assert!((without.r_squared - dropped.r_squared).abs() < 1e-12);
+        assert!((without.covariance - dropped.covariance).frobenius_norm() < 1e-12);
+
+        // heavy weights pull the line through their points
+        let pulled = weighted_least_squares(&xs, &ys, &[1e9, 1.0, 1.0, 1e9]).unwrap();
+        assert!((1.0 - pulled.slope).abs() < 1e-6);
+        assert!(pulled.intercept.abs() < 1e-6);
+
+        assert_eq!(None, weighted_least_squares(&xs[..3], &ys[..3], &[1.0, -1.0, 1.0]));
+        assert_eq!(None, weighted_least_squares(&xs, &ys, &[1.0, f64::INFINITY, 1.0, 1.0]));
+        assert_eq!(None, weighted_least_squares(&xs, &ys, &[1.0, f64::NAN, 1.0, 1.0]));
+        assert_eq!(None, weighted_least_squares(&xs, &ys, &[1.0; 3]));
+        assert_eq!(None, weighted_least_squares(&xs, &ys[..2], &[1.0; 4]));
+    }
+}
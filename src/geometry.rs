@@ -0,0 +1,523 @@
+use std::cmp::Ordering;
+
+use modular::extended_gcd;
+use Matrix;
+use Point2;
+use Transform2;
+use Vector;
+
+/// The rotational sense of a sequence of points.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Orientation {
+    Clockwise,
+    CounterClockwise,
+    Collinear
+}
+
+/// An axis-aligned bounding box, given by its lower-left and upper-right corners.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Aabb {
+    min: Vector<f64>,
+    max: Vector<f64>
+}
+
+/// A closed polygon, given by its vertices in order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polygon {
+    vertices: Vec<Vector<f64>>
+}
+
+/// An open chain of line segments, given by its vertices in order.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Polyline {
+    vertices: Vec<Vector<f64>>
+}
+
+/// A half-line starting at origin and running along direction.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Ray2 {
+    origin: Vector<f64>,
+    direction: Vector<f64>
+}
+
+
+impl Aabb {
+    pub fn new(min: Vector<f64>, max: Vector<f64>) -> Aabb {
+        Aabb {
+            min,
+            max
+        }
+    }
+
+    /// The smallest box containing every point, or None if there are none.
+    pub fn from_points(points: &[Vector<f64>]) -> Option<Aabb> {
+        let first = match points.first() {
+            Some(&p) => p,
+            None     => return None
+        };
+
+        Some(points.iter().fold(Aabb::new(first, first), |acc, p| {
+            Aabb::new(
+                Vector::new(acc.min.x.min(p.x), acc.min.y.min(p.y)),
+                Vector::new(acc.max.x.max(p.x), acc.max.y.max(p.y))
+            )
+        }))
+    }
+
+    pub fn min(&self) -> Vector<f64> {
+        self.min
+    }
+
+    pub fn max(&self) -> Vector<f64> {
+        self.max
+    }
+
+    /// Width and height.
+    pub fn size(&self) -> Vector<f64> {
+        self.max - self.min
+    }
+
+    pub fn center(&self) -> Vector<f64> {
+        (self.min + self.max) * 0.5
+    }
+
+    /// Corners in counter-clockwise order, starting from min.
+    pub fn corners(&self) -> [Vector<f64>; 4] {
+        [
+            self.min,
+            Vector::new(self.max.x, self.min.y),
+            self.max,
+            Vector::new(self.min.x, self.max.y)
+        ]
+    }
+
+    pub fn contains(&self, p: Vector<f64>) -> bool {
+        self.min.x <= p.x && p.x <= self.max.x && self.min.y <= p.y && p.y <= self.max.y
+    }
+}
+
+impl Ray2 {
+    pub fn new(origin: Vector<f64>, direction: Vector<f64>) -> Ray2 {
+        Ray2 {
+            origin,
+            direction
+        }
+    }
+
+    pub fn origin(&self) -> Vector<f64> {
+        self.origin
+    }
+
+    pub fn direction(&self) -> Vector<f64> {
+        self.direction
+    }
+
+    /// The point origin + t * direction.
+    pub fn at(&self, t: f64) -> Vector<f64> {
+        self.origin + self.direction * t
+    }
+
+    /// The image of the ray under t: the origin moves as a point and the
+    /// direction by the linear part only, so the new at(s) is the image of the
+    /// old one.
+    ///
+    pub fn transform<M>(&self, t: &M) -> Ray2 where M: Transform2<f64> {
+        Ray2::new(t.transform_point(Point2::from_vector(self.origin)).to_vector(), t.transform_vector(self.direction))
+    }
+
+    /// The parameter at which the ray crosses the segment a -> b, or None if it
+    /// misses or runs parallel to it.
+    ///
+    pub fn intersect_segment(&self, a: Vector<f64>, b: Vector<f64>) -> Option<f64> {
+        line_parameters(self.origin, self.direction, a, b - a)
+            .filter(|&(t, s)| t >= 0.0 && (0.0..=1.0).contains(&s))
+            .map(|(t, _)| t)
+    }
+
+    /// The parameters (enter, exit) between which the ray is inside aabb, with
+    /// enter = 0 if it starts inside, or None if it misses.
+    ///
+    pub fn intersect_aabb(&self, aabb: &Aabb) -> Option<(f64, f64)> {
+        // clip [0, inf) against each pair of parallel sides in turn
+        let slab = |origin: f64, direction: f64, min: f64, max: f64, (enter, exit): (f64, f64)| {
+            if direction == 0.0 {
+                if min <= origin && origin <= max { Some((enter, exit)) } else { None }
+            } else {
+                let (t0, t1) = ((min - origin) / direction, (max - origin) / direction);
+                let (near, far) = if t0 <= t1 { (t0, t1) } else { (t1, t0) };
+                let (enter, exit) = (enter.max(near), exit.min(far));
+
+                if enter <= exit { Some((enter, exit)) } else { None }
+            }
+        };
+
+        slab(self.origin.x, self.direction.x, aabb.min.x, aabb.max.x, (0.0, f64::INFINITY))
+            .and_then(|range| slab(self.origin.y, self.direction.y, aabb.min.y, aabb.max.y, range))
+    }
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Vector<f64>>) -> Polygon {
+        Polygon {
+            vertices
+        }
+    }
+
+    pub fn vertices(&self) -> &[Vector<f64>] {
+        &self.vertices
+    }
+
+    /// The polygon with every vertex mapped through t.
+    pub fn transform<M>(&self, t: &M) -> Polygon where M: Transform2<f64> {
+        Polygon::new(self.vertices.iter().map(|&v| t.transform_point(Point2::from_vector(v)).to_vector()).collect())
+    }
+
+    /// Shoelace area, positive when the vertices run counter-clockwise.
+    pub fn signed_area(&self) -> f64 {
+        self.edges().map(|(p, q)| p.perp_dot(q)).sum::<f64>() / 2.0
+    }
+
+    pub fn area(&self) -> f64 {
+        self.signed_area().abs()
+    }
+
+    /// Centre of mass of the enclosed region, or None if it has no area.
+    pub fn centroid(&self) -> Option<Vector<f64>> {
+        let area = self.signed_area();
+
+        if area == 0.0 {
+            return None;
+        }
+
+        let sum = self.edges().fold(Vector::new(0.0, 0.0), |acc, (p, q)| {
+            acc + (p + q) * p.perp_dot(q)
+        });
+
+        Some(sum * (1.0 / (6.0 * area)))
+    }
+
+    pub fn winding(&self) -> Orientation {
+        let area = self.signed_area();
+
+        if area > 0.0 {
+            Orientation::CounterClockwise
+        } else if area < 0.0 {
+            Orientation::Clockwise
+        } else {
+            Orientation::Collinear
+        }
+    }
+
+    /// Consecutive vertex pairs, including the closing edge from last to first.
+    fn edges<'a>(&'a self) -> impl Iterator<Item=(Vector<f64>, Vector<f64>)> + 'a {
+        let n = self.vertices.len();
+
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+}
+
+impl Polyline {
+    pub fn new(vertices: Vec<Vector<f64>>) -> Polyline {
+        Polyline {
+            vertices
+        }
+    }
+
+    pub fn vertices(&self) -> &[Vector<f64>] {
+        &self.vertices
+    }
+
+    /// The polyline with every vertex mapped through t.
+    pub fn transform<M>(&self, t: &M) -> Polyline where M: Transform2<f64> {
+        Polyline::new(self.vertices.iter().map(|&v| t.transform_point(Point2::from_vector(v)).to_vector()).collect())
+    }
+
+    /// Total length of the segments.
+    pub fn length(&self) -> f64 {
+        self.vertices.windows(2).map(|w| w[0].distance(w[1])).sum()
+    }
+}
+
+/// The orientation of the turn p -> q -> r, computed exactly.
+///
+/// This is the sign of the determinant of [q - p, r - p]. The differences are
+/// taken in i128, and the two products of the determinant compared as u128
+/// magnitudes, so no coordinates in i64 can overflow.
+///
+pub fn orient2d(p: Vector<i64>, q: Vector<i64>, r: Vector<i64>) -> Orientation {
+    let widen = |v: Vector<i64>| Vector::new(v.x as i128, v.y as i128);
+    let (p, q, r) = (widen(p), widen(q), widen(r));
+    let m = Matrix::from_vectors(q - p, r - p);
+
+    match product_key(m.a, m.d).cmp(&product_key(m.b, m.c)) {
+        Ordering::Greater => Orientation::CounterClockwise,
+        Ordering::Less    => Orientation::Clockwise,
+        Ordering::Equal   => Orientation::Collinear
+    }
+}
+
+/// The point where the lines p1 + t d1 and p2 + s d2 cross, or None if they
+/// are parallel.
+///
+pub fn intersect_lines(p1: Vector<f64>, d1: Vector<f64>, p2: Vector<f64>, d2: Vector<f64>) -> Option<Vector<f64>> {
+    line_parameters(p1, d1, p2, d2).map(|(t, _)| p1 + d1 * t)
+}
+
+/// The parameters (t, s), both in [0, 1], at which the segments a0 -> a1 and
+/// b0 -> b1 cross, so the crossing is at a0 + t (a1 - a0) = b0 + s (b1 - b0).
+///
+/// Returns None if the segments miss or are parallel, even when overlapping.
+///
+pub fn intersect_segments(a0: Vector<f64>, a1: Vector<f64>, b0: Vector<f64>, b1: Vector<f64>) -> Option<(f64, f64)> {
+    line_parameters(a0, a1 - a0, b0, b1 - b0)
+        .filter(|&(t, s)| (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&s))
+}
+
+/// The area of the triangle abc, positive when a -> b -> c runs counter-clockwise.
+pub fn signed_area(a: Vector<f64>, b: Vector<f64>, c: Vector<f64>) -> f64 {
+    (b - a).perp_dot(c - a) / 2.0
+}
+
+pub fn triangle_area(a: Vector<f64>, b: Vector<f64>, c: Vector<f64>) -> f64 {
+    signed_area(a, b, c).abs()
+}
+
+/// Twice the signed area of the lattice triangle abc, which is an integer.
+///
+/// Exact whenever the differences of the coordinates fit in i64.
+///
+pub fn doubled_signed_area(a: Vector<i64>, b: Vector<i64>, c: Vector<i64>) -> i128 {
+    let widen = |v: Vector<i64>| Vector::new(v.x as i128, v.y as i128);
+
+    Matrix::from_vectors(widen(b - a), widen(c - a)).determinant()
+}
+
+/// The numbers of lattice points (interior, boundary) of the lattice triangle
+/// abc, by Pick's theorem: area = interior + boundary / 2 - 1.
+///
+/// A degenerate triangle has no interior, and its boundary is counted along
+/// the segment joining its extreme vertices.
+///
+pub fn lattice_points(a: Vector<i64>, b: Vector<i64>, c: Vector<i64>) -> (i128, i128) {
+    let doubled_area = doubled_signed_area(a, b, c).abs();
+
+    // a segment from p to q passes through gcd(|dx|, |dy|) + 1 lattice points
+    let steps = |p: Vector<i64>, q: Vector<i64>| {
+        let d = q - p;
+        extended_gcd(d.x, d.y).0 as i128
+    };
+    let (ab, bc, ca) = (steps(a, b), steps(b, c), steps(c, a));
+
+    if doubled_area == 0 {
+        return (0, ab.max(bc).max(ca) + 1);
+    }
+
+    let boundary = ab + bc + ca;
+
+    ((doubled_area - boundary + 2) / 2, boundary)
+}
+
+/// The weights (u, v, w), summing to 1, with p = u a + v b + w c, or None if
+/// the triangle abc is degenerate.
+///
+pub fn barycentric(p: Vector<f64>, a: Vector<f64>, b: Vector<f64>, c: Vector<f64>) -> Option<(f64, f64, f64)> {
+    Matrix::from_vectors(b - a, c - a).solve(p - a).map(|vw| (1.0 - vw.x - vw.y, vw.x, vw.y))
+}
+
+/// Whether p lies in the triangle abc, boundary included. A degenerate
+/// triangle contains nothing.
+///
+pub fn is_inside_triangle(p: Vector<f64>, a: Vector<f64>, b: Vector<f64>, c: Vector<f64>) -> bool {
+    match barycentric(p, a, b, c) {
+        Some((u, v, w)) => u >= 0.0 && v >= 0.0 && w >= 0.0,
+        None            => false
+    }
+}
+
+/// The (t, s) with p1 + t d1 = p2 + s d2, or None if d1 and d2 are parallel.
+fn line_parameters(p1: Vector<f64>, d1: Vector<f64>, p2: Vector<f64>, d2: Vector<f64>) -> Option<(f64, f64)> {
+    Matrix::from_vectors(d1, d2 * -1.0).solve(p2 - p1).map(|v| (v.x, v.y))
+}
+
+/// A key ordering the products x * y, each factor below 2^64 in magnitude,
+/// without ever computing the possibly overflowing signed product.
+///
+fn product_key(x: i128, y: i128) -> (bool, u128) {
+    let magnitude = x.unsigned_abs() * y.unsigned_abs();
+
+    if (x < 0) != (y < 0) && magnitude != 0 {
+        (false, u128::MAX - magnitude)
+    } else {
+        (true, magnitude)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use geometry::{barycentric, intersect_lines, intersect_segments, is_inside_triangle, orient2d};
+    use geometry::{doubled_signed_area, lattice_points, signed_area, triangle_area};
+    use geometry::{Aabb, Orientation, Polygon, Polyline, Ray2};
+    use linear_transforms::rotation;
+    use Affine2;
+    use Matrix;
+    use Vector;
+
+    fn square() -> Polygon {
+        Polygon::new(vec![
+            Vector::new(0.0, 0.0),
+            Vector::new(2.0, 0.0),
+            Vector::new(2.0, 2.0),
+            Vector::new(0.0, 2.0)
+        ])
+    }
+
+    #[test]
+    fn polygon() {
+        let p = square();
+        let flipped = p.transform(&Matrix::new(-1.0, 0.0, 0.0, 1.0));
+
+        assert_eq!(4.0, p.area());
+        assert_eq!(Some(Vector::new(1.0, 1.0)), p.centroid());
+        assert_eq!(Orientation::CounterClockwise, p.winding());
+
+        assert_eq!(-4.0, flipped.signed_area());
+        assert_eq!(Orientation::Clockwise, flipped.winding());
+        assert_eq!(Some(Vector::new(-1.0, 1.0)), flipped.centroid());
+
+        assert_eq!(16.0, p.transform(&Matrix::new(2.0, 0.0, 0.0, 2.0)).area());
+        assert!((4.0 - p.transform(&rotation(0.3)).area()).abs() < 1e-12);
+        assert_eq!(Some(Vector::new(4.0, 0.0)), p.transform(&Affine2::from_translation(Vector::new(3.0, -1.0))).centroid());
+
+        let line = Polygon::new(vec![Vector::new(0.0, 0.0), Vector::new(1.0, 1.0)]);
+        assert_eq!(Orientation::Collinear, line.winding());
+        assert_eq!(None, line.centroid());
+    }
+
+    #[test]
+    fn aabb() {
+        let b = Aabb::new(Vector::new(0.0, 0.0), Vector::new(2.0, 1.0));
+
+        assert_eq!(Some(b), Aabb::from_points(&b.corners()));
+        assert_eq!(None, Aabb::from_points(&[]));
+        assert!(b.contains(Vector::new(1.0, 0.5)));
+        assert!(!b.contains(Vector::new(1.0, 1.5)));
+        assert_eq!(Vector::new(2.0, 1.0), b.size());
+        assert_eq!(Vector::new(1.0, 0.5), b.center());
+
+        assert_eq!(
+            Aabb::new(Vector::new(-1.0, 0.0), Vector::new(0.0, 2.0)),
+            Matrix::new(0.0, -1.0, 1.0, 0.0).transform_aabb(&b)
+        );
+        assert_eq!(
+            Aabb::new(Vector::new(0.0, -1.0), Vector::new(3.0, 2.0)),
+            Matrix::new(1.0, 1.0, 1.0, -1.0).transform_aabb(&b)
+        );
+    }
+
+    #[test]
+    fn polyline() {
+        let l = Polyline::new(vec![
+            Vector::new(0.0, 0.0),
+            Vector::new(3.0, 4.0),
+            Vector::new(3.0, 0.0)
+        ]);
+
+        assert_eq!(9.0, l.length());
+        assert_eq!(18.0, l.transform(&Matrix::new(2.0, 0.0, 0.0, 2.0)).length());
+        assert_eq!(Vector::new(-4.0, 3.0), l.transform(&Matrix::new(0.0, -1.0, 1.0, 0.0)).vertices()[1]);
+    }
+
+    #[test]
+    fn orientation() {
+        let (o, x, y) = (Vector::new(0, 0), Vector::new(1, 0), Vector::new(0, 1));
+
+        assert_eq!(Orientation::CounterClockwise, orient2d(o, x, y));
+        assert_eq!(Orientation::Clockwise,        orient2d(o, y, x));
+        assert_eq!(Orientation::Collinear,        orient2d(o, x, Vector::new(5, 0)));
+
+        // products of these differences overflow i64
+        let big = i64::MAX / 2;
+        let p = Vector::new(-big, -big);
+        let q = Vector::new(big, big);
+        assert_eq!(Orientation::Collinear,        orient2d(p, q, Vector::new(0, 0)));
+        assert_eq!(Orientation::CounterClockwise, orient2d(p, q, Vector::new(0, 1)));
+        assert_eq!(Orientation::Clockwise,        orient2d(p, q, Vector::new(1, 0)));
+
+        // the extreme coordinates give products near 2^128
+        let (min, max) = (i64::MIN, i64::MAX);
+        assert_eq!(Orientation::CounterClockwise, orient2d(Vector::new(min, min), Vector::new(max, min), Vector::new(max, max)));
+        assert_eq!(Orientation::Clockwise,        orient2d(Vector::new(min, min), Vector::new(min, max), Vector::new(max, max)));
+        assert_eq!(Orientation::Collinear,        orient2d(Vector::new(min, min), Vector::new(max, max), Vector::new(0, 0)));
+    }
+
+    #[test]
+    fn intersections() {
+        let o = Vector::new(0.0, 0.0);
+
+        assert_eq!(Some(Vector::new(1.0, 1.0)), intersect_lines(o, Vector::new(1.0, 1.0), Vector::new(2.0, 0.0), Vector::new(-1.0, 1.0)));
+        assert_eq!(None, intersect_lines(o, Vector::new(1.0, 2.0), Vector::new(1.0, 0.0), Vector::new(2.0, 4.0)));
+
+        let (a0, a1) = (Vector::new(0.0, 0.0), Vector::new(4.0, 0.0));
+        assert_eq!(Some((0.25, 0.5)), intersect_segments(a0, a1, Vector::new(1.0, -1.0), Vector::new(1.0, 1.0)));
+        assert_eq!(None, intersect_segments(a0, a1, Vector::new(5.0, -1.0), Vector::new(5.0, 1.0)));
+        assert_eq!(None, intersect_segments(a0, a1, Vector::new(1.0, 1.0), Vector::new(1.0, 2.0)));
+        assert_eq!(Some((1.0, 0.0)), intersect_segments(a0, a1, a1, Vector::new(4.0, 3.0)));
+    }
+
+    #[test]
+    fn triangles() {
+        let (a, b, c) = (Vector::new(0.0, 0.0), Vector::new(4.0, 0.0), Vector::new(0.0, 4.0));
+
+        assert_eq!(Some((1.0, 0.0, 0.0)), barycentric(a, a, b, c));
+        assert_eq!(Some((0.5, 0.25, 0.25)), barycentric(Vector::new(1.0, 1.0), a, b, c));
+        assert_eq!(Some((0.0, 0.5, 0.5)), barycentric(Vector::new(2.0, 2.0), a, b, c));
+        assert_eq!(None, barycentric(a, a, b, Vector::new(8.0, 0.0)));
+
+        assert!(is_inside_triangle(Vector::new(1.0, 1.0), a, b, c));
+        assert!(is_inside_triangle(Vector::new(2.0, 2.0), a, b, c));
+        assert!(!is_inside_triangle(Vector::new(3.0, 3.0), a, b, c));
+        assert!(!is_inside_triangle(a, a, b, Vector::new(8.0, 0.0)));
+    }
+
+    #[test]
+    fn areas() {
+        let (a, b, c) = (Vector::new(0.0, 0.0), Vector::new(4.0, 0.0), Vector::new(1.0, 3.0));
+
+        assert_eq!(6.0, signed_area(a, b, c));
+        assert_eq!(-6.0, signed_area(a, c, b));
+        assert_eq!(6.0, triangle_area(a, c, b));
+
+        let (p, q, r) = (Vector::new(0, 0), Vector::new(4, 0), Vector::new(1, 3));
+        assert_eq!(12, doubled_signed_area(p, q, r));
+        assert_eq!(-12, doubled_signed_area(p, r, q));
+
+        // boundary: 4 along the base, 1 up the left side, 3 down the right
+        assert_eq!((3, 8), lattice_points(p, q, r));
+        assert_eq!((0, 3), lattice_points(p, Vector::new(1, 0), Vector::new(0, 1)));
+        assert_eq!((0, 5), lattice_points(p, Vector::new(2, 2), Vector::new(4, 4)));
+
+        let big = 1 << 40;
+        assert_eq!(1 << 80, doubled_signed_area(p, Vector::new(big, 0), Vector::new(0, big)));
+    }
+
+    #[test]
+    fn rays() {
+        let ray = Ray2::new(Vector::new(0.0, 0.0), Vector::new(2.0, 1.0));
+
+        assert_eq!(Vector::new(4.0, 2.0), ray.at(2.0));
+        assert_eq!(Some(1.5), ray.intersect_segment(Vector::new(3.0, -5.0), Vector::new(3.0, 5.0)));
+        assert_eq!(None, ray.intersect_segment(Vector::new(-3.0, -5.0), Vector::new(-3.0, 5.0)));
+        assert_eq!(None, ray.intersect_segment(Vector::new(3.0, 2.0), Vector::new(3.0, 5.0)));
+
+        let t = Affine2::new(Matrix::new(0.0, -1.0, 1.0, 0.0), Vector::new(10.0, 0.0));
+        let moved = ray.transform(&t);
+        assert_eq!(Vector::new(10.0, 0.0), moved.origin());
+        assert_eq!(Vector::new(8.0, 4.0), moved.at(2.0));
+
+        let b = Aabb::new(Vector::new(2.0, -1.0), Vector::new(4.0, 4.0));
+        assert_eq!(Some((1.0, 2.0)), ray.intersect_aabb(&b));
+        assert_eq!(Some((0.0, 1.0)), Ray2::new(Vector::new(3.0, 0.0), Vector::new(1.0, 0.0)).intersect_aabb(&b));
+        assert_eq!(None, Ray2::new(Vector::new(0.0, 5.0), Vector::new(1.0, 0.0)).intersect_aabb(&b));
+        assert_eq!(None, Ray2::new(Vector::new(5.0, 0.0), Vector::new(1.0, 0.0)).intersect_aabb(&b));
+    }
+}
@@ -0,0 +1,292 @@
+/// A matrix over GF(2), the field {0, 1} with XOR for addition and AND for
+/// multiplication, such as a parity-check matrix or a lights-out puzzle.
+///
+/// Entries are packed 64 to a word, row by row, so row operations work a
+/// word at a time. Like Banded it has any size and stands apart from the
+/// fixed-size types.
+///
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Gf2Matrix {
+    rows: usize,
+    cols: usize,
+    words: Vec<u64>
+}
+
+
+impl Gf2Matrix {
+    /// The rows by cols zero matrix.
+    pub fn new(rows: usize, cols: usize) -> Gf2Matrix {
+        Gf2Matrix {
+            rows,
+            cols,
+            words: vec![0; rows * Gf2Matrix::row_words(cols)]
+        }
+    }
+
+    pub fn identity(n: usize) -> Gf2Matrix {
+        let mut m = Gf2Matrix::new(n, n);
+        for i in 0..n {
+            m.set(i, i, true);
+        }
+
+        m
+    }
+
+    /// The matrix with the given rows, or None unless they have the same
+    /// length.
+    ///
+    pub fn from_rows(rows: &[Vec<bool>]) -> Option<Gf2Matrix> {
+        let cols = rows.first().map_or(0, |r| r.len());
+        if rows.iter().any(|r| r.len() != cols) {
+            return None;
+        }
+
+        let mut m = Gf2Matrix::new(rows.len(), cols);
+        for (i, row) in rows.iter().enumerate() {
+            for (j, &x) in row.iter().enumerate() {
+                m.set(i, j, x);
+            }
+        }
+
+        Some(m)
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// # Panics
+    ///
+    /// If (i, j) lies outside the matrix.
+    ///
+    pub fn get(&self, i: usize, j: usize) -> bool {
+        let (k, bit) = self.index(i, j);
+
+        self.words[k] >> bit & 1 == 1
+    }
+
+    /// # Panics
+    ///
+    /// If (i, j) lies outside the matrix.
+    ///
+    pub fn set(&mut self, i: usize, j: usize, x: bool) {
+        let (k, bit) = self.index(i, j);
+
+        if x {
+            self.words[k] |= 1 << bit;
+        } else {
+            self.words[k] &= !(1 << bit);
+        }
+    }
+
+    pub fn transpose(&self) -> Gf2Matrix {
+        let mut t = Gf2Matrix::new(self.cols, self.rows);
+        for i in 0..self.rows {
+            for j in 0..self.cols {
+                t.set(j, i, self.get(i, j));
+            }
+        }
+
+        t
+    }
+
+    /// The entrywise XOR self + rhs, or None if the shapes differ.
+    pub fn sum(&self, rhs: &Gf2Matrix) -> Option<Gf2Matrix> {
+        if self.rows != rhs.rows || self.cols != rhs.cols {
+            return None;
+        }
+
+        let mut m = self.clone();
+        for (w, r) in m.words.iter_mut().zip(&rhs.words) {
+            *w ^= r;
+        }
+
+        Some(m)
+    }
+
+    /// The product self * rhs, or None if the shapes don't match.
+    ///
+    /// Row i of the product is the XOR of the rows of rhs picked out by row
+    /// i of self.
+    ///
+    pub fn product(&self, rhs: &Gf2Matrix) -> Option<Gf2Matrix> {
+        if self.cols != rhs.rows {
+            return None;
+        }
+
+        let mut m = Gf2Matrix::new(self.rows, rhs.cols);
+        let w = Gf2Matrix::row_words(rhs.cols);
+        for i in 0..self.rows {
+            for j in (0..self.cols).filter(|&j| self.get(i, j)) {
+                for k in 0..w {
+                    m.words[i * w + k] ^= rhs.words[j * w + k];
+                }
+            }
+        }
+
+        Some(m)
+    }
+
+    /// The product self * x, or None if x has the wrong length.
+    pub fn mul_vector(&self, x: &[bool]) -> Option<Vec<bool>> {
+        if x.len() != self.cols {
+            return None;
+        }
+
+        Some((0..self.rows).map(|i| (0..self.cols).filter(|&j| self.get(i, j) && x[j]).count() % 2 == 1).collect())
+    }
+
+    pub fn rank(&self) -> usize {
+        self.clone().row_reduce().len()
+    }
+
+    /// The inverse, or None unless self is square and nonsingular.
+    pub fn inverse(&self) -> Option<Gf2Matrix> {
+        let n = self.rows;
+        if self.cols != n {
+            return None;
+        }
+
+        // reduce [self | I] to [I | self^-1]
+        let mut augmented = Gf2Matrix::new(n, 2 * n);
+        for i in 0..n {
+            for j in 0..n {
+                augmented.set(i, j, self.get(i, j));
+            }
+            augmented.set(i, n + i, true);
+        }
+
+        if augmented.row_reduce().iter().take_while(|&&p| p < n).count() != n {
+            return None;
+        }
+
+        let mut m = Gf2Matrix::new(n, n);
+        for i in 0..n {
+            for j in 0..n {
+                m.set(i, j, augmented.get(i, n + j));
+            }
+        }
+
+        Some(m)
+    }
+
+    /// A basis of the x with self * x = 0, one vector per free column of the
+    /// reduced row echelon form.
+    ///
+    pub fn kernel(&self) -> Vec<Vec<bool>> {
+        let mut r = self.clone();
+        let pivots = r.row_reduce();
+
+        (0..self.cols).filter(|f| !pivots.contains(f)).map(|f| {
+            let mut x = vec![false; self.cols];
+            x[f] = true;
+            for (i, &p) in pivots.iter().enumerate() {
+                x[p] = r.get(i, f);
+            }
+
+            x
+        }).collect()
+    }
+
+    // brings self to reduced row echelon form, returning the pivot columns
+    fn row_reduce(&mut self) -> Vec<usize> {
+        let mut pivots = Vec::new();
+
+        for j in 0..self.cols {
+            let r = pivots.len();
+            let p = match (r..self.rows).find(|&i| self.get(i, j)) {
+                Some(p) => p,
+                None => continue
+            };
+
+            self.swap_rows(r, p);
+            for i in 0..self.rows {
+                if i != r && self.get(i, j) {
+                    self.xor_row(i, r);
+                }
+            }
+            pivots.push(j);
+        }
+
+        pivots
+    }
+
+    fn swap_rows(&mut self, i: usize, j: usize) {
+        let w = Gf2Matrix::row_words(self.cols);
+        for k in 0..w {
+            self.words.swap(i * w + k, j * w + k);
+        }
+    }
+
+    // adds row src to row dst
+    fn xor_row(&mut self, dst: usize, src: usize) {
+        let w = Gf2Matrix::row_words(self.cols);
+        for k in 0..w {
+            self.words[dst * w + k] ^= self.words[src * w + k];
+        }
+    }
+
+    fn index(&self, i: usize, j: usize) -> (usize, usize) {
+        assert!(i < self.rows && j < self.cols, "entry outside the matrix");
+
+        (i * Gf2Matrix::row_words(self.cols) + j / 64, j % 64)
+    }
+
+    fn row_words(cols: usize) -> usize {
+        cols.div_ceil(64)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use gf2::Gf2Matrix;
+
+    fn bits(s: &str) -> Vec<bool> {
+        s.chars().map(|c| c == '1').collect()
+    }
+
+    #[test]
+    fn arithmetic() {
+        let a = Gf2Matrix::from_rows(&[bits("110"), bits("011")]).unwrap();
+        let b = Gf2Matrix::from_rows(&[bits("10"), bits("11"), bits("01")]).unwrap();
+
+        assert_eq!(Gf2Matrix::from_rows(&[bits("01"), bits("10")]), a.product(&b));
+        assert_eq!(None, a.product(&a));
+        assert_eq!(Some(Gf2Matrix::new(2, 3)), a.sum(&a));
+        assert_eq!(Some(bits("10")), a.mul_vector(&bits("100")));
+        assert_eq!(b, a.transpose());
+        assert_eq!(None, Gf2Matrix::from_rows(&[bits("1"), bits("10")]));
+    }
+
+    #[test]
+    fn elimination() {
+        // wide enough to span two words per row
+        let n = 70;
+        let mut m = Gf2Matrix::identity(n);
+        for i in 0..n - 1 {
+            m.set(i, i + 1, true);
+        }
+        let inverse = m.inverse().unwrap();
+
+        assert_eq!(n, m.rank());
+        assert_eq!(Some(Gf2Matrix::identity(n)), m.product(&inverse));
+        assert!(inverse.get(0, n - 1));
+
+        // the (7, 4) Hamming code's parity checks
+        let h = Gf2Matrix::from_rows(&[bits("1010101"), bits("0110011"), bits("0001111")]).unwrap();
+        let kernel = h.kernel();
+
+        assert_eq!(3, h.rank());
+        assert_eq!(4, kernel.len());
+        for x in &kernel {
+            assert_eq!(Some(vec![false; 3]), h.mul_vector(x));
+        }
+        assert_eq!(None, h.inverse());
+        assert_eq!(None, Gf2Matrix::from_rows(&[bits("11"), bits("11")]).unwrap().inverse());
+    }
+}
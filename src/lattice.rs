@@ -0,0 +1,98 @@
+use std::convert::TryFrom;
+
+use Matrix;
+use Vector;
+
+/// A Gauss (Lagrange) reduced basis for the lattice spanned by the columns of basis.
+///
+/// The first column of the result is a shortest non-zero lattice vector and
+/// the second is a shortest vector independent of it. The columns of basis
+/// should be linearly independent.
+///
+/// # Panics
+///
+/// If a vector formed along the way has an entry outside i64.
+///
+pub fn reduce(basis: Matrix<i64>) -> Matrix<i64> {
+    let (mut u, mut v) = (basis.left(), basis.right());
+
+    if norm_squared(v) < norm_squared(u) {
+        ::std::mem::swap(&mut u, &mut v);
+    }
+
+    while norm_squared(u) != 0 {
+        // subtract the multiple of u nearest to v's projection onto it
+        let m = rounded_quotient(dot(u, v), norm_squared(u));
+        v = subtract_multiple(v, u, m);
+
+        if norm_squared(v) >= norm_squared(u) {
+            break;
+        }
+        ::std::mem::swap(&mut u, &mut v);
+    }
+
+    Matrix::from_vectors(u, v)
+}
+
+/// A shortest non-zero vector in the lattice spanned by the columns of basis.
+pub fn shortest_vector(basis: Matrix<i64>) -> Vector<i64> {
+    reduce(basis).left()
+}
+
+// dot products are taken in i128, as they can overflow i64 long before the
+// entries themselves do
+fn dot(u: Vector<i64>, v: Vector<i64>) -> i128 {
+    u.x as i128 * v.x as i128 + u.y as i128 * v.y as i128
+}
+
+fn norm_squared(u: Vector<i64>) -> i128 {
+    dot(u, u)
+}
+
+/// v - m u, formed in i128 as m itself may not fit in i64.
+fn subtract_multiple(v: Vector<i64>, u: Vector<i64>, m: i128) -> Vector<i64> {
+    let entry = |v: i64, u: i64| i64::try_from(v as i128 - m * u as i128).expect("lattice vector entry outside i64");
+
+    Vector::new(entry(v.x, u.x), entry(v.y, u.y))
+}
+
+/// p / q rounded to the nearest integer, for q > 0.
+fn rounded_quotient(p: i128, q: i128) -> i128 {
+    (2 * p + q).div_euclid(2 * q)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use lattice::{reduce, shortest_vector};
+    use Matrix;
+    use Vector;
+
+    #[test]
+    fn reduction() {
+        // a skewed basis of the square lattice
+        let skewed = Matrix::new(5, 8, 3, 5);
+        let reduced = reduce(skewed);
+
+        assert_eq!(1, reduced.left().dot(reduced.left()));
+        assert_eq!(1, reduced.right().dot(reduced.right()));
+        assert_eq!(1, reduced.determinant().abs());
+
+        // reduced bases are only sorted
+        assert_eq!(Matrix::new(2, 0, 0, 3), reduce(Matrix::new(2, 0, 0, 3)));
+        assert_eq!(Matrix::new(2, 0, 0, 3), reduce(Matrix::new(0, 2, 3, 0)));
+
+        // the multiple of u to subtract, 2^63, is itself outside i64
+        assert_eq!(Matrix::new(-1, 0, 0, 1), reduce(Matrix::new(-1, i64::MIN, 0, 1)));
+    }
+
+    #[test]
+    fn shortest() {
+        let v = shortest_vector(Matrix::new(1, 101, 2, 199));
+
+        assert_eq!(2, v.dot(v));
+        assert!(v == Vector::new(1, -1) || v == Vector::new(-1, 1));
+
+        assert_eq!(Vector::new(0, 0), shortest_vector(Matrix::new(0, 0, 0, 0)));
+    }
+}
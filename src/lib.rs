@@ -1,15 +1,98 @@
+#[cfg(test)]
+extern crate bincode;
+#[cfg(feature = "bytemuck")]
+extern crate bytemuck;
+#[cfg(test)]
+extern crate postcard;
+
 use std::fmt;
 use std::fmt::Display;
-use std::ops::{Add, Mul};
+use std::ops::{Add, Mul, Neg, Sub};
+
+use geometry::Aabb;
+use linear_transforms::rotation;
+use modular::extended_gcd;
+use num::{LeBytes, One, Scalar, Semiring, Zero};
 
-mod linear_transforms;
+#[cfg(any(test, feature = "testing"))]
+#[macro_use]
+pub mod testing;
+
+pub mod algebra;
+pub mod banded;
+pub mod conics;
+pub mod crypto;
+pub mod dynamics;
+pub mod encoding;
+pub mod expr;
+pub mod fit;
+pub mod geometry;
+pub mod gf2;
+pub mod lattice;
+pub mod linear_transforms;
+pub mod mechanics;
+#[cfg(feature = "matrix-stack")]
+pub mod matrix_stack;
+pub mod modular;
+pub mod num;
+pub mod number_theory;
+pub mod operator;
+pub mod quantum;
+pub mod recurrence;
+pub mod sl2z;
+pub mod statistics;
+pub mod svg;
+pub mod symmetry;
+pub mod tropical;
+mod affine;
+mod camera;
+mod complex;
+mod diagonal;
+mod matrix3;
+mod matrix4;
+mod nonsingular;
+mod orthogonal;
+mod plane;
+#[cfg(feature = "bytemuck")]
+mod pod;
+mod point;
+mod quaternion;
+mod stochastic;
+mod symmetric;
+mod transform;
+mod transform_cache;
+mod transformed;
+mod triangular;
+mod unit_vector;
+
+pub use affine::{Affine2, Isometry2, Isometry3, TransformStack};
+pub use camera::Camera2;
+pub use complex::Complex;
+pub use diagonal::Diagonal2;
+pub use linear_transforms::Rotation2;
+pub use matrix3::{EulerOrder, Matrix3, Vector3};
+pub use matrix4::{DepthRange, Matrix4};
+pub use nonsingular::NonSingular;
+pub use orthogonal::Orthogonal;
+pub use plane::Plane;
+pub use point::Point2;
+pub use quaternion::Quaternion;
+pub use stochastic::StochasticMatrix;
+pub use symmetric::Symmetric2;
+pub use transform::Transform2;
+pub use transform_cache::TransformCache;
+pub use transformed::{TransformIterator, TransformedBy};
+pub use triangular::{LowerTriangular2, UpperTriangular2};
+pub use unit_vector::UnitVector;
 
 /// Represents a 2x2 matrix with entries of type T.
 ///
-/// Internally stored as: [[a, b], [c, d]]
+/// Internally stored as: [[a, b], [c, d]], and laid out in that row-major
+/// order, as the struct is repr(C).
 ///
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
-struct Matrix<T> where T: Copy {
+#[repr(C)]
+pub struct Matrix<T> where T: Copy {
     a: T,
     b: T,
     c: T,
@@ -21,25 +104,90 @@ struct Matrix<T> where T: Copy {
 /// Internally stored as: transpose([x, y])
 ///
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
-struct Vector<T> where T: Copy {
+#[repr(C)]
+pub struct Vector<T> where T: Copy {
     x: T,
     y: T
 }
 
+/// The sign behaviour of the quadratic form x^t A x of a symmetric matrix A.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Definiteness {
+    PositiveDefinite,
+    PositiveSemiDefinite,
+    Indefinite,
+    NegativeSemiDefinite,
+    NegativeDefinite
+}
+
+/// The eigenvalues of a real 2x2 matrix.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Eigenvalues {
+    /// Two different real eigenvalues, larger first.
+    RealDistinct(f64, f64),
+
+    /// A single real eigenvalue of multiplicity two.
+    RealRepeated(f64),
+
+    /// A complex conjugate pair, positive imaginary part first.
+    ComplexConjugate(Complex<f64>, Complex<f64>)
+}
+
+impl Eigenvalues {
+    /// Both eigenvalues, larger first, if they are real.
+    pub fn real(&self) -> Option<(f64, f64)> {
+        match *self {
+            Eigenvalues::RealDistinct(l1, l2)  => Some((l1, l2)),
+            Eigenvalues::RealRepeated(l)       => Some((l, l)),
+            Eigenvalues::ComplexConjugate(..)  => None
+        }
+    }
+}
+
+/// The most steps Matrix::inverse_iteration takes.
+const INVERSE_ITERATION_MAX_STEPS: usize = 100;
+
+/// An eigenpair found iteratively, with how the iteration went.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EigenEstimate {
+    pub eigenvalue: f64,
+
+    /// Unit length.
+    pub eigenvector: Vector<f64>,
+
+    /// Matrix-vector steps taken.
+    pub iterations: usize,
+
+    /// The length of A v - lambda v at the final estimate.
+    pub residual: f64,
+
+    /// Whether the residual fell to the requested tolerance.
+    pub converged: bool
+}
+
 
 // Vanilla Methods
 
 impl<T> Matrix<T> where T: Copy {
-    fn new(a: T, b: T, c: T, d: T) -> Matrix<T> {
+    pub fn new(a: T, b: T, c: T, d: T) -> Matrix<T> {
         Matrix {
-            a: a,
-            b: b,
-            c: c,
-            d: d
+            a,
+            b,
+            c,
+            d
         }
     }
 
-    fn from_vectors(left: Vector<T>, right: Vector<T>) -> Matrix<T> {
+    pub fn identity() -> Matrix<T> where T: Zero + One {
+        Matrix::new(
+            T::one(),
+            T::zero(),
+            T::zero(),
+            T::one()
+        )
+    }
+
+    pub fn from_vectors(left: Vector<T>, right: Vector<T>) -> Matrix<T> {
         Matrix::new(
             left.x,
             right.x,
@@ -48,7 +196,30 @@ impl<T> Matrix<T> where T: Copy {
         )
     }
 
-    fn scale(&self, factor: T) -> Matrix<T> where T: Mul<Output=T> {
+    /// The matrix [[re, -im], [im, re]] of multiplication by z, which
+    /// rotates by arg(z) and scales by |z|.
+    ///
+    pub fn from_complex(z: Complex<T>) -> Matrix<T> where T: Neg<Output=T> {
+        Matrix::new(
+            z.re(),
+            -z.im(),
+            z.im(),
+            z.re()
+        )
+    }
+
+    /// The complex number this matrix multiplies by, if it has the form
+    /// [[re, -im], [im, re]].
+    ///
+    pub fn to_complex(&self) -> Option<Complex<T>> where T: PartialEq + Neg<Output=T> {
+        if self.a == self.d && self.b == -self.c {
+            Some(Complex::new(self.a, self.c))
+        } else {
+            None
+        }
+    }
+
+    pub fn scale(&self, factor: T) -> Matrix<T> where T: Mul<Output=T> {
         Matrix::new(
             factor * self.a,
             factor * self.b,
@@ -57,7 +228,7 @@ impl<T> Matrix<T> where T: Copy {
         )
     }
 
-    fn transpose(&self) -> Matrix<T> {
+    pub fn transpose(&self) -> Matrix<T> {
         Matrix::new(
             self.a,
             self.c,
@@ -66,173 +237,1964 @@ impl<T> Matrix<T> where T: Copy {
         )
     }
 
-    fn left(&self) -> Vector<T> {
+    pub fn left(&self) -> Vector<T> {
         Vector::new(
             self.a,
             self.c
         )
     }
 
-    fn right(&self) -> Vector<T> {
+    pub fn right(&self) -> Vector<T> {
         Vector::new(
             self.b,
             self.d
         )
     }
+
+    pub fn determinant(&self) -> T where T: Mul<Output=T> + Sub<Output=T> {
+        self.a * self.d - self.b * self.c
+    }
+
+    pub fn trace(&self) -> T where T: Add<Output=T> {
+        self.a + self.d
+    }
+
+    /// The quadratic form v^t A v.
+    pub fn quadratic_form(&self, v: Vector<T>) -> T where T: Mul<Output=T> + Add<Output=T> {
+        self.bilinear_form(v, v)
+    }
+
+    /// The bilinear form u^t A v.
+    pub fn bilinear_form(&self, u: Vector<T>, v: Vector<T>) -> T where T: Mul<Output=T> + Add<Output=T> {
+        u.dot(*self * v)
+    }
+
+    /// Classifies a symmetric matrix by Sylvester's criterion on its leading
+    /// minors a and det; the result is meaningless for non-symmetric matrices.
+    ///
+    /// The zero matrix is both positive and negative semi-definite, and is
+    /// reported as PositiveSemiDefinite.
+    ///
+    pub fn definiteness(&self) -> Definiteness where T: Zero + PartialOrd + Mul<Output=T> + Sub<Output=T> {
+        let zero = T::zero();
+        let det = self.determinant();
+
+        if det > zero {
+            if self.a > zero { Definiteness::PositiveDefinite } else { Definiteness::NegativeDefinite }
+        } else if det < zero {
+            Definiteness::Indefinite
+        } else if self.a < zero || self.d < zero {
+            Definiteness::NegativeSemiDefinite
+        } else {
+            Definiteness::PositiveSemiDefinite
+        }
+    }
+
+    /// Raises to the nth power by repeated squaring.
+    pub fn pow(&self, n: u64) -> Matrix<T> where T: Semiring {
+        let mut result = Matrix::identity();
+        let mut base = *self;
+        let mut n = n;
+
+        while n > 0 {
+            if n & 1 == 1 {
+                result = result * base;
+            }
+            n >>= 1;
+            if n > 0 {
+                base = base * base;
+            }
+        }
+
+        result
+    }
+
+    /// The orbit x0, A x0, A^2 x0, ... of x0 under repeated application of
+    /// this matrix, as an endless iterator.
+    ///
+    pub fn orbit(&self, x0: Vector<T>) -> impl Iterator<Item=Vector<T>> where T: Mul<Output=T> + Add<Output=T> {
+        let m = *self;
+
+        ::std::iter::successors(Some(x0), move |&x| Some(m * x))
+    }
+
+    /// Kronecker product, represented as a 2x2 matrix of 2x2 blocks:
+    /// [[a * other, b * other], [c * other, d * other]]
+    ///
+    pub fn kronecker(&self, other: &Matrix<T>) -> Matrix<Matrix<T>> where T: Mul<Output=T> {
+        Matrix::new(
+            other.scale(self.a),
+            other.scale(self.b),
+            other.scale(self.c),
+            other.scale(self.d)
+        )
+    }
+
+    /// The commutator [self, other] = self * other - other * self.
+    pub fn commutator(&self, other: &Matrix<T>) -> Matrix<T> where T: Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+        *self * *other - *other * *self
+    }
+
+    /// The anticommutator {self, other} = self * other + other * self.
+    pub fn anticommutator(&self, other: &Matrix<T>) -> Matrix<T> where T: Mul<Output=T> + Add<Output=T> {
+        *self * *other + *other * *self
+    }
+
+    /// Whether self * other == other * self exactly; see approx_commutes_with
+    /// for floats.
+    ///
+    pub fn commutes_with(&self, other: &Matrix<T>) -> bool where T: PartialEq + Mul<Output=T> + Add<Output=T> {
+        *self * *other == *other * *self
+    }
+
+    /// This matrix as an SVG transform, matrix(a,c,b,d,0,0) in SVG's
+    /// column-major order, for the linear part of a transform.
+    ///
+    pub fn to_svg_matrix(&self) -> String where T: Display {
+        format!("matrix({},{},{},{},0,0)", self.a, self.c, self.b, self.d)
+    }
+
+    /// The entries a, b, c, d in that order, each little-endian and fixed
+    /// width, so 4 * T::SIZE bytes in all. This is bincode's default,
+    /// fixed-width encoding of the array [a, b, c, d], and for floats
+    /// postcard's too; postcard writes integers as varints instead.
+    ///
+    pub fn to_bytes(&self) -> Vec<u8> where T: LeBytes {
+        let mut bytes = Vec::with_capacity(4 * T::SIZE);
+
+        for x in &[self.a, self.b, self.c, self.d] {
+            x.write_le(&mut bytes);
+        }
+
+        bytes
+    }
+
+    /// The matrix encoded by to_bytes, or None if bytes has the wrong length.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Matrix<T>> where T: LeBytes {
+        if bytes.len() != 4 * T::SIZE {
+            return None;
+        }
+
+        let entry = |i: usize| T::read_le(&bytes[i * T::SIZE..]);
+
+        Some(Matrix::new(entry(0)?, entry(1)?, entry(2)?, entry(3)?))
+    }
 }
 
 impl<T> Vector<T> where T: Copy {
-    fn new(x: T, y: T) -> Vector<T> {
+    pub fn new(x: T, y: T) -> Vector<T> {
         Vector {
-            x: x,
-            y: y
+            x,
+            y
         }
     }
 
-    fn scale(&self, factor: T) -> Vector<T> where T: Mul<Output=T> {
+    pub fn scale(&self, factor: T) -> Vector<T> where T: Mul<Output=T> {
         Vector {
             x: factor * self.x,
             y: factor * self.y
         }
     }
-}
 
-// Display Methods
+    pub fn dot(&self, other: Vector<T>) -> T where T: Mul<Output=T> + Add<Output=T> {
+        self.x * other.x + self.y * other.y
+    }
 
-impl<T> fmt::Display for Matrix<T> where T: Copy + Display {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "[[{} {}], [{} {}]]", self.a, self.b, self.c, self.d)
+    /// Rotated by 90 degrees counter-clockwise: (-y, x).
+    pub fn perp(&self) -> Vector<T> where T: Neg<Output=T> {
+        Vector::new(
+            -self.y,
+            self.x
+        )
     }
-}
 
-impl<T> fmt::Display for Vector<T> where T: Copy + Display {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "[{} {}]^t", self.x, self.y)
+    /// Rotated by 90 degrees clockwise: (y, -x).
+    pub fn perp_cw(&self) -> Vector<T> where T: Neg<Output=T> {
+        Vector::new(
+            self.y,
+            -self.x
+        )
     }
-}
 
-// Operator Methods
+    pub fn distance_squared(&self, other: Vector<T>) -> T where T: Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+        let delta = *self - other;
 
-/// Implementation of Matrix + Matrix.
-impl<T> Add<Matrix<T>> for Matrix<T> where T: Copy + Add<Output=T> {
-    type Output = Matrix<T>;
+        delta.dot(delta)
+    }
 
-    fn add(self, rhs: Matrix<T>) -> Matrix<T> {
-        Matrix::new(
-            self.a + rhs.a,
-            self.b + rhs.b,
-            self.c + rhs.c,
-            self.d + rhs.d
-        )
+    /// The z component of the 3D cross product, i.e. det([self other]).
+    pub fn perp_dot(&self, other: Vector<T>) -> T where T: Mul<Output=T> + Sub<Output=T> {
+        self.x * other.y - self.y * other.x
     }
-}
 
-/// Implementation of Vector + Vector.
-impl<T> Add<Vector<T>> for Vector<T> where T: Copy + Add<Output=T> {
-    type Output = Vector<T>;
+    /// The components x, y in that order, each little-endian, so 2 * T::SIZE
+    /// bytes in all.
+    ///
+    pub fn to_bytes(&self) -> Vec<u8> where T: LeBytes {
+        let mut bytes = Vec::with_capacity(2 * T::SIZE);
 
-    fn add(self, rhs: Vector<T>) -> Vector<T> {
-        Vector::new(
-            self.x + rhs.x,
-            self.y + rhs.y
-        )
+        self.x.write_le(&mut bytes);
+        self.y.write_le(&mut bytes);
+
+        bytes
     }
-}
 
-/// Implementation of Matrix * Scalar.
-impl<T> Mul<T> for Matrix<T> where T: Copy + Mul<Output=T> {
-    type Output = Matrix<T>;
+    /// The vector encoded by to_bytes, or None if bytes has the wrong length.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Vector<T>> where T: LeBytes {
+        if bytes.len() != 2 * T::SIZE {
+            return None;
+        }
 
-    fn mul(self, rhs: T) -> Matrix<T> {
-        Matrix::new(
-            rhs * self.a,
-            rhs * self.b,
-            rhs * self.c,
-            rhs * self.d
-        )
+        Some(Vector::new(T::read_le(bytes)?, T::read_le(&bytes[T::SIZE..])?))
     }
 }
 
-/// Implementation of Matrix * Vector.
-impl<T> Mul<Vector<T>> for Matrix<T> where T: Copy + Mul<Output=T> + Add<Output=T> {
-    type Output = Vector<T>;
+impl<T> Zero for Matrix<T> where T: Copy + Zero {
+    fn zero() -> Matrix<T> {
+        Matrix::new(T::zero(), T::zero(), T::zero(), T::zero())
+    }
+}
 
-    fn mul(self, rhs: Vector<T>) -> Vector<T> {
-        Vector::new(
-            self.a * rhs.x + self.b * rhs.y,
-            self.c * rhs.x + self.d * rhs.y
-        )
+impl<T> One for Matrix<T> where T: Copy + Zero + One {
+    fn one() -> Matrix<T> {
+        Matrix::identity()
     }
 }
 
-/// Implementation of Matrix * Matrix.
-impl<T> Mul<Matrix<T>> for Matrix<T> where T: Copy + Mul<Output=T> + Add<Output=T> {
-    type Output = Matrix<T>;
+// Float Methods
 
-    fn mul(self, rhs: Matrix<T>) -> Matrix<T> {
+impl Matrix<f64> {
+    /// self * b + c in one pass, each entry as two fused multiply-adds.
+    ///
+    /// f64::mul_add rounds once per step, using the FMA instruction where
+    /// the target has one, so each entry is rounded twice rather than the
+    /// four times of the separate products and sums.
+    ///
+    pub fn mul_add(&self, b: &Matrix<f64>, c: &Matrix<f64>) -> Matrix<f64> {
         Matrix::new(
-            self.a * rhs.a + self.b * rhs.c,
-            self.a * rhs.b + self.b * rhs.d,
-            self.c * rhs.a + self.d * rhs.c,
-            self.c * rhs.b + self.d * rhs.d
+            self.a.mul_add(b.a, self.b.mul_add(b.c, c.a)),
+            self.a.mul_add(b.b, self.b.mul_add(b.d, c.b)),
+            self.c.mul_add(b.a, self.d.mul_add(b.c, c.c)),
+            self.c.mul_add(b.b, self.d.mul_add(b.d, c.d))
         )
     }
-}
-
-/// Implementation of Vector * Scalar.
-impl<T> Mul<T> for Vector<T> where T: Copy + Mul<Output=T> {
-    type Output = Vector<T>;
 
-    fn mul(self, rhs: T) -> Vector<T> {
+    /// self * x + y in one pass, as in mul_add.
+    pub fn mul_add_vector(&self, x: Vector<f64>, y: Vector<f64>) -> Vector<f64> {
         Vector::new(
-            rhs * self.x,
-            rhs * self.y
+            self.a.mul_add(x.x, self.b.mul_add(x.y, y.x)),
+            self.c.mul_add(x.x, self.d.mul_add(x.y, y.y))
         )
     }
-}
 
+    /// Square root of the sum of squared entries.
+    pub fn frobenius_norm(&self) -> f64 {
+        (self.a * self.a + self.b * self.b + self.c * self.c + self.d * self.d).sqrt()
+    }
 
-#[cfg(test)]
-mod tests {
-    use Matrix;
-    use Vector;
+    /// Maximum absolute column sum.
+    pub fn norm_one(&self) -> f64 {
+        (self.a.abs() + self.c.abs()).max(self.b.abs() + self.d.abs())
+    }
 
-    #[test]
-    fn initialize_stuff() {
-        // combining vectors
-        assert_eq!(
-            Matrix::new(1, 2, 3, 4),
-            Matrix::from_vectors(Vector::new(1, 3), Vector::new(2, 4))
-        );
+    /// Maximum absolute row sum.
+    pub fn norm_inf(&self) -> f64 {
+        (self.a.abs() + self.b.abs()).max(self.c.abs() + self.d.abs())
+    }
 
-        // splitting matricies
-        assert_eq!(
-            Vector::new(1, 3),
-            Matrix::new(1, 2, 3, 4).left()
-        );
-        assert_eq!(
-            Vector::new(2, 4),
-            Matrix::new(1, 2, 3, 4).right()
-        );
+    /// Largest singular value.
+    ///
+    /// The squared singular values are the roots of x^2 - |A|_F^2 x + det(A)^2.
+    ///
+    pub fn spectral_norm(&self) -> f64 {
+        let f2 = self.a * self.a + self.b * self.b + self.c * self.c + self.d * self.d;
+        let det = self.determinant();
+        let discriminant = (f2 * f2 - 4.0 * det * det).max(0.0);
+
+        ((f2 + discriminant.sqrt()) / 2.0).sqrt()
     }
 
-    #[test]
-    fn manipulate_stuff() {
-        let m: Matrix<u32> = Matrix::new(1, 2, 3, 4);
-        let v: Vector<u32> = Vector::new(5, 6);
+    /// The determinant ad - bc to within about one rounding error, using
+    /// Kahan's fused multiply-add algorithm to avoid cancellation.
+    ///
+    pub fn determinant_accurate(&self) -> f64 {
+        difference_of_products(self.a, self.d, self.b, self.c)
+    }
 
-        // scaling
-        assert_eq!(Matrix::new(10, 20, 30, 40), m.scale(10));
-        assert_eq!(Vector::new(50, 60),         v.scale(10));
+    /// Whether the commutator with other has Frobenius norm at most epsilon.
+    pub fn approx_commutes_with(&self, other: &Matrix<f64>, epsilon: f64) -> bool {
+        self.commutator(other).frobenius_norm() <= epsilon
+    }
 
-        // adding
-        assert_eq!(Matrix::new(2, 4, 6, 8),     m + m);
-        assert_eq!(Vector::new(10, 12),         v + v);
+    /// The inverse matrix, or None if this one is singular.
+    pub fn inverse(&self) -> Option<Matrix<f64>> {
+        let det = self.determinant();
 
-        // multiplying
-        assert_eq!(Matrix::new(10, 20, 30, 40), m * 10);
-        assert_eq!(Vector::new(50, 60),         v * 10);
-        assert_eq!(Matrix::new(7, 10, 15, 22),  m * m);
-        assert_eq!(Vector::new(17, 39),         m * v);
+        if det == 0.0 {
+            return None;
+        }
+
+        Some(Matrix::new(self.d, -self.b, -self.c, self.a) * (1.0 / det))
+    }
+
+    /// The inverse transpose, which maps surface normals the way this matrix
+    /// maps tangents, or None if this matrix is singular.
+    ///
+    pub fn normal_matrix(&self) -> Option<Matrix<f64>> {
+        self.inverse().map(|inverse| inverse.transpose())
+    }
+
+    /// The x solving self * x = b, or None if this matrix is singular.
+    pub fn solve(&self, b: Vector<f64>) -> Option<Vector<f64>> {
+        let det = self.determinant();
+
+        if det == 0.0 {
+            return None;
+        }
+
+        // Cramer's rule
+        Some(Vector::new(
+            (b.x * self.d - self.b * b.y) / det,
+            (self.a * b.y - b.x * self.c) / det
+        ))
+    }
+
+    /// The x solving self * x = b by Gaussian elimination with partial
+    /// pivoting, with the relative residual |A x - b| / |b|, or None if this
+    /// matrix is singular.
+    ///
+    /// A residual far above machine epsilon signals an ill-conditioned system.
+    ///
+    pub fn solve_pivoted(&self, b: Vector<f64>) -> Option<(Vector<f64>, f64)> {
+        // put the row with the larger leading entry first
+        let (top, bottom) = (Vector::new(self.a, self.b), Vector::new(self.c, self.d));
+        let (r1, r2, b1, b2) = if self.a.abs() >= self.c.abs() {
+            (top, bottom, b.x, b.y)
+        } else {
+            (bottom, top, b.y, b.x)
+        };
+
+        if r1.x == 0.0 {
+            return None;
+        }
+
+        let multiplier = r2.x / r1.x;
+        let pivot = r2.y - multiplier * r1.y;
+
+        if pivot == 0.0 {
+            return None;
+        }
+
+        let y = (b2 - multiplier * b1) / pivot;
+        let x = Vector::new((b1 - r1.y * y) / r1.x, y);
+
+        let b_length = b.length();
+        let residual = (*self * x - b).length();
+
+        Some((x, if b_length == 0.0 { residual } else { residual / b_length }))
+    }
+
+    /// The matrix taking coordinates relative to the basis from to coordinates
+    /// relative to the basis to, or None if to is not a basis.
+    ///
+    pub fn change_of_basis(from: (Vector<f64>, Vector<f64>), to: (Vector<f64>, Vector<f64>)) -> Option<Matrix<f64>> {
+        Matrix::from_vectors(to.0, to.1).inverse()
+            .map(|inverse| inverse * Matrix::from_vectors(from.0, from.1))
+    }
+
+    /// This map expressed in the given basis, B^-1 * self * B where B has the
+    /// basis vectors as columns, or None if they are not a basis.
+    ///
+    pub fn in_basis(&self, basis: (Vector<f64>, Vector<f64>)) -> Option<Matrix<f64>> {
+        self.conjugate_by(&Matrix::from_vectors(basis.0, basis.1))
+    }
+
+    /// The similarity transform P^-1 * self * P, or None if p is singular.
+    pub fn conjugate_by(&self, p: &Matrix<f64>) -> Option<Matrix<f64>> {
+        p.inverse().map(|inverse| inverse * *self * *p)
+    }
+
+    /// The tightest axis-aligned box containing the image of aabb.
+    pub fn transform_aabb(&self, aabb: &Aabb) -> Aabb {
+        let corners = aabb.corners();
+        let mapped = [
+            *self * corners[0],
+            *self * corners[1],
+            *self * corners[2],
+            *self * corners[3]
+        ];
+
+        Aabb::from_points(&mapped).unwrap()
+    }
+
+    pub fn eigenvalues(&self) -> Eigenvalues {
+        let half_trace = self.trace() / 2.0;
+        let half_gap = (self.a - self.d) / 2.0;
+
+        // (tr/2)^2 - det, rearranged to avoid cancellation when a and d are close
+        let discriminant = half_gap * half_gap + self.b * self.c;
+
+        if discriminant > 0.0 {
+            let root = discriminant.sqrt();
+
+            Eigenvalues::RealDistinct(half_trace + root, half_trace - root)
+        } else if discriminant == 0.0 {
+            Eigenvalues::RealRepeated(half_trace)
+        } else {
+            let root = (-discriminant).sqrt();
+
+            Eigenvalues::ComplexConjugate(
+                Complex::new(half_trace, root),
+                Complex::new(half_trace, -root)
+            )
+        }
+    }
+
+    /// Singular value decomposition A = U diag(s1, s2) V^t, returned as (U, (s1, s2), V).
+    ///
+    /// U and V are orthogonal, U is a rotation, and s1 >= s2 >= 0.
+    ///
+    pub fn svd(&self) -> (Matrix<f64>, (f64, f64), Matrix<f64>) {
+        // split A into a rotation-scaling [[e, -h], [h, e]] plus a
+        // reflection-scaling [[f, g], [g, -f]]; their angles give U and V
+        let e = (self.a + self.d) / 2.0;
+        let f = (self.a - self.d) / 2.0;
+        let g = (self.b + self.c) / 2.0;
+        let h = (self.c - self.b) / 2.0;
+
+        let q = e.hypot(h);
+        let r = f.hypot(g);
+        let a1 = g.atan2(f);
+        let a2 = h.atan2(e);
+
+        let u = rotation((a2 + a1) / 2.0);
+        let v = rotation((a1 - a2) / 2.0);
+
+        if q >= r {
+            (u, (q + r, q - r), v)
+        } else {
+            // a negative second singular value moves into a reflection in V
+            (u, (q + r, r - q), Matrix::from_vectors(v.left(), v.right() * -1.0))
+        }
+    }
+
+    /// The largest modulus of an eigenvalue.
+    pub fn spectral_radius(&self) -> f64 {
+        match self.eigenvalues() {
+            Eigenvalues::RealDistinct(l1, l2)    => l1.abs().max(l2.abs()),
+            Eigenvalues::RealRepeated(l)         => l.abs(),
+            Eigenvalues::ComplexConjugate(z, _)  => z.abs()
+        }
+    }
+
+    /// Whether the powers of this matrix tend to zero, so that x -> Ax converges
+    /// to the origin from every starting point: true exactly when the spectral
+    /// radius is below 1.
+    ///
+    pub fn converges_under_iteration(&self) -> bool {
+        self.spectral_radius() < 1.0
+    }
+
+    /// The matrix function f(A), if the eigenvalues of A are real.
+    ///
+    /// As apply_fn_with_derivative, with f' at a repeated eigenvalue l
+    /// estimated by a central difference with step 1e-5 |l| (1e-5 at zero),
+    /// which stays in the domain of functions such as ln and sqrt for l > 0.
+    /// Pass the derivative where one is known.
+    ///
+    pub fn apply_fn<F>(&self, f: F) -> Option<Matrix<f64>> where F: Fn(f64) -> f64 {
+        let derivative = |l: f64| {
+            let h = if l == 0.0 { 1e-5 } else { 1e-5 * l.abs() };
+
+            (f(l + h) - f(l - h)) / (2.0 * h)
+        };
+
+        self.apply_fn_with_derivative(&f, derivative)
+    }
+
+    /// The matrix function f(A), if the eigenvalues of A are real, given the
+    /// derivative df of f.
+    ///
+    /// With distinct eigenvalues l1, l2 this is Sylvester's formula
+    /// (f(l1) (A - l2 I) - f(l2) (A - l1 I)) / (l1 - l2). A repeated eigenvalue l
+    /// gives f(l) I + df(l) (A - l I).
+    ///
+    pub fn apply_fn_with_derivative<F, D>(&self, f: F, df: D) -> Option<Matrix<f64>> where F: Fn(f64) -> f64, D: Fn(f64) -> f64 {
+        let identity: Matrix<f64> = Matrix::identity();
+
+        match self.eigenvalues() {
+            Eigenvalues::RealDistinct(l1, l2) => {
+                let p1 = *self - identity.scale(l2);
+                let p2 = *self - identity.scale(l1);
+
+                Some((p1.scale(f(l1)) - p2.scale(f(l2))).scale(1.0 / (l1 - l2)))
+            },
+
+            Eigenvalues::RealRepeated(l) => {
+                let nilpotent = *self - identity.scale(l);
+
+                if nilpotent == Matrix::new(0.0, 0.0, 0.0, 0.0) {
+                    return Some(identity.scale(f(l)));
+                }
+
+                Some(identity.scale(f(l)) + nilpotent.scale(df(l)))
+            },
+
+            Eigenvalues::ComplexConjugate(..) => None
+        }
+    }
+
+    /// The matrix exponential e^A.
+    ///
+    /// Unlike apply_fn this handles complex eigenvalues, using the closed form
+    /// e^s (cos(q) I + sin(q) / q (A - s I)) with s = tr(A) / 2 and q the
+    /// imaginary part of the eigenvalues (and its hyperbolic analogue when
+    /// they are real).
+    ///
+    pub fn exp(&self) -> Matrix<f64> {
+        let s = self.trace() / 2.0;
+        let half_gap = (self.a - self.d) / 2.0;
+        let discriminant = half_gap * half_gap + self.b * self.c;
+        let shifted = *self - Matrix::identity().scale(s);
+
+        let (even, odd) = if discriminant > 0.0 {
+            let q = discriminant.sqrt();
+            (q.cosh(), q.sinh() / q)
+        } else if discriminant < 0.0 {
+            let q = (-discriminant).sqrt();
+            (q.cos(), q.sin() / q)
+        } else {
+            (1.0, 1.0)
+        };
+
+        (Matrix::identity().scale(even) + shifted.scale(odd)).scale(s.exp())
+    }
+
+    /// The principal square root, if the eigenvalues are real; entries are NaN
+    /// if either is negative.
+    pub fn sqrt(&self) -> Option<Matrix<f64>> {
+        self.apply_fn_with_derivative(f64::sqrt, |x| 0.5 / x.sqrt())
+    }
+
+    /// The principal logarithm, if the eigenvalues are real; entries are NaN
+    /// unless both are positive.
+    pub fn ln(&self) -> Option<Matrix<f64>> {
+        self.apply_fn_with_derivative(f64::ln, |x| 1.0 / x)
+    }
+
+    /// A^p for real p, if the eigenvalues are real and non-negative.
+    pub fn powf(&self, p: f64) -> Option<Matrix<f64>> {
+        self.apply_fn_with_derivative(|x| x.powf(p), |x| p * x.powf(p - 1.0))
+    }
+
+    /// The Givens rotation G with G * [a, b] = [r, 0], r >= 0, returned with its
+    /// cosine and sine as (G, c, s), where G = [[c, s], [-s, c]].
+    ///
+    pub fn givens(a: f64, b: f64) -> (Matrix<f64>, f64, f64) {
+        let r = a.hypot(b);
+        let (c, s) = if r == 0.0 { (1.0, 0.0) } else { (a / r, b / r) };
+
+        (Matrix::new(c, s, -s, c), c, s)
+    }
+
+    /// The Householder reflector I - 2 v v^t / (v^t v), reflecting in the line
+    /// perpendicular to v. Entries are NaN if v is zero.
+    ///
+    pub fn householder(v: Vector<f64>) -> Matrix<f64> {
+        let k = 2.0 / v.dot(v);
+
+        Matrix::new(
+            1.0 - k * v.x * v.x, -k * v.x * v.y,
+            -k * v.y * v.x,      1.0 - k * v.y * v.y
+        )
+    }
+
+    /// The orthogonal projection onto the line along u, u u^t.
+    pub fn projection_onto(u: UnitVector<f64>) -> Matrix<f64> {
+        let (x, y) = (u.x(), u.y());
+
+        Matrix::new(x * x, x * y, x * y, y * y)
+    }
+
+    /// The reflection across the line along u, 2 u u^t - I.
+    pub fn reflection_across(u: UnitVector<f64>) -> Matrix<f64> {
+        let (x, y) = (u.x(), u.y());
+
+        Matrix::new(x * x - y * y, 2.0 * x * y, 2.0 * x * y, y * y - x * x)
+    }
+
+    /// The Cayley transform (I + A)(I - A)^-1, or None if 1 is an eigenvalue.
+    ///
+    /// Takes the skew-symmetric matrix [[0, -t], [t, 0]] to the rotation by
+    /// 2 atan(t), so rotations other than the half turn have a smooth
+    /// unconstrained parameter.
+    ///
+    pub fn cayley(&self) -> Option<Matrix<f64>> {
+        let identity: Matrix<f64> = Matrix::identity();
+
+        (identity - *self).inverse().map(|inverse| (identity + *self) * inverse)
+    }
+
+    /// The inverse Cayley transform (A - I)(A + I)^-1, or None if -1 is an
+    /// eigenvalue. Takes rotations back to skew-symmetric matrices.
+    ///
+    pub fn inverse_cayley(&self) -> Option<Matrix<f64>> {
+        let identity: Matrix<f64> = Matrix::identity();
+
+        (*self + identity).inverse().map(|inverse| (*self - identity) * inverse)
+    }
+
+    /// Estimates the eigenpair of largest absolute eigenvalue by power iteration.
+    ///
+    /// Starts from the unit vector at an angle of 1 radian, and stops once the
+    /// residual is at most tol, or after max_iters steps, but always takes at
+    /// least one. Convergence is linear in the ratio of the eigenvalues, and
+    /// fails when they share an absolute value.
+    ///
+    pub fn power_iteration(&self, tol: f64, max_iters: usize) -> EigenEstimate {
+        operator::power_iteration(self, tol, max_iters)
+    }
+
+    /// Estimates the eigenpair whose eigenvalue is nearest shift, by inverse
+    /// iteration on A - shift I.
+    ///
+    /// Stops once the residual is at most tol, or after 100 steps. A shift
+    /// that is exactly an eigenvalue is answered directly, with no iterations.
+    ///
+    pub fn inverse_iteration(&self, shift: f64, tol: f64) -> EigenEstimate {
+        let shifted = *self - Matrix::identity().scale(shift);
+        let mut v = Vector::new(1.0, 1.0).normalize();
+        let mut iterations = 0;
+
+        loop {
+            match shifted.solve(v) {
+                Some(w) => {
+                    v = w.normalize();
+                    iterations += 1;
+                },
+                None    => v = self.eigenvector(shift)
+            }
+
+            let av = *self * v;
+            let eigenvalue = v.dot(av);
+            let residual = (av - v * eigenvalue).length();
+
+            if residual <= tol || iterations == 0 || iterations >= INVERSE_ITERATION_MAX_STEPS {
+                return EigenEstimate {
+                    eigenvalue,
+                    eigenvector: v,
+                    iterations,
+                    residual,
+                    converged: residual <= tol
+                };
+            }
+        }
+    }
+
+    /// The Jacobi rotation diagonalizing a symmetric matrix, as
+    /// (theta, (l1, l2), Q) with self = Q diag(l1, l2) Q^t, l1 >= l2, and Q the
+    /// rotation by theta, whose columns are the eigenvectors.
+    ///
+    /// Only the symmetric part (A + A^t) / 2 is used.
+    ///
+    pub fn symmetric_eigen(&self) -> (f64, (f64, f64), Matrix<f64>) {
+        let off_diagonal = (self.b + self.c) / 2.0;
+        let half_gap = (self.a - self.d) / 2.0;
+        let mean = (self.a + self.d) / 2.0;
+        let radius = half_gap.hypot(off_diagonal);
+        let theta = off_diagonal.atan2(half_gap) / 2.0;
+
+        (theta, (mean + radius, mean - radius), rotation(theta))
+    }
+
+    /// Returns (P, D) with self = P * D * P^-1 and D diagonal, if this matrix is
+    /// diagonalizable over the reals.
+    ///
+    /// The columns of P are unit eigenvectors; D holds the eigenvalues, larger
+    /// first. Matrices with complex eigenvalues, and defective ones such as
+    /// [[1, 1], [0, 1]], give None.
+    ///
+    pub fn diagonalize(&self) -> Option<(Matrix<f64>, Matrix<f64>)> {
+        match self.eigenvalues() {
+            Eigenvalues::RealDistinct(l1, l2) => Some((
+                Matrix::from_vectors(self.eigenvector(l1), self.eigenvector(l2)),
+                Matrix::new(l1, 0.0, 0.0, l2)
+            )),
+
+            // a repeated eigenvalue has two independent eigenvectors only for
+            // multiples of the identity
+            Eigenvalues::RealRepeated(_) if self.b == 0.0 && self.c == 0.0 => Some((
+                Matrix::identity(),
+                *self
+            )),
+
+            _ => None
+        }
+    }
+
+    pub fn is_diagonalizable(&self) -> bool {
+        self.diagonalize().is_some()
+    }
+
+    /// Returns (P, J) with self = P * J * P^-1 and J in Jordan normal form, if
+    /// the eigenvalues are real.
+    ///
+    /// Diagonalizable matrices give the same result as diagonalize. A defective
+    /// matrix, with repeated eigenvalue lambda but only one eigenvector, gives
+    /// J = [[lambda, 1], [0, lambda]], and P = [v w] where v is an eigenvector
+    /// and w a generalized eigenvector with (self - lambda I) w = v.
+    ///
+    pub fn jordan_form(&self) -> Option<(Matrix<f64>, Matrix<f64>)> {
+        if let Some(diagonal) = self.diagonalize() {
+            return Some(diagonal);
+        }
+
+        match self.eigenvalues() {
+            Eigenvalues::RealRepeated(lambda) => {
+                let nilpotent = *self - Matrix::identity().scale(lambda);
+
+                // any w outside the kernel of the nilpotent part will do
+                let w = if nilpotent.left() != Vector::new(0.0, 0.0) {
+                    Vector::new(1.0, 0.0)
+                } else {
+                    Vector::new(0.0, 1.0)
+                };
+
+                Some((
+                    Matrix::from_vectors(nilpotent * w, w),
+                    Matrix::new(lambda, 1.0, 0.0, lambda)
+                ))
+            },
+
+            _ => None
+        }
+    }
+
+    /// Real Schur decomposition: returns (Q, T) with self = Q * T * Q^t, where Q
+    /// is a rotation and T is quasi-upper-triangular.
+    ///
+    /// With real eigenvalues T is upper triangular, the larger eigenvalue first.
+    /// With complex eigenvalues alpha +- beta i, T is the standardised block
+    /// [[alpha, b], [c, alpha]] with b * c = -beta^2.
+    ///
+    pub fn schur(&self) -> (Matrix<f64>, Matrix<f64>) {
+        match self.eigenvalues().real() {
+            Some((l1, _)) => {
+                let v = self.eigenvector(l1);
+                let q = Matrix::from_vectors(v, v.perp());
+                let t = q.transpose() * *self * q;
+
+                // the lower entry is zero up to rounding
+                (q, Matrix::new(t.a, t.b, 0.0, t.d))
+            },
+
+            None => {
+                // rotating by theta changes the diagonal difference a - d to
+                // (a - d) cos(2 theta) + (b + c) sin(2 theta); choose theta to zero it
+                let two_theta = (self.d - self.a).atan2(self.b + self.c);
+                let q = rotation(two_theta / 2.0);
+
+                (q, q.transpose() * *self * q)
+            }
+        }
+    }
+
+    /// A unit eigenvector for the eigenvalue lambda.
+    ///
+    /// Any unit vector is an eigenvector of a multiple of the identity, and then
+    /// [1 0]^t is returned.
+    ///
+    pub fn eigenvector(&self, lambda: f64) -> Vector<f64> {
+        let top = Vector::new(self.a - lambda, self.b);
+        let bottom = Vector::new(self.c, self.d - lambda);
+
+        // the eigenvector is perpendicular to both rows of A - lambda I; use the
+        // longer row for accuracy
+        let row = if top.dot(top) >= bottom.dot(bottom) { top } else { bottom };
+
+        if row.dot(row) == 0.0 {
+            Vector::new(1.0, 0.0)
+        } else {
+            row.perp().normalize()
+        }
+    }
+}
+
+impl Vector<f64> {
+    pub fn length(&self) -> f64 {
+        self.dot(*self).sqrt()
+    }
+
+    /// The unit vector in the same direction.
+    pub fn normalize(&self) -> Vector<f64> {
+        *self * (1.0 / self.length())
+    }
+
+    pub fn distance(&self, other: Vector<f64>) -> f64 {
+        self.distance_squared(other).sqrt()
+    }
+
+    /// Unsigned angle to other, in radians within [0, pi].
+    pub fn angle(&self, other: Vector<f64>) -> f64 {
+        self.perp_dot(other).abs().atan2(self.dot(other))
+    }
+
+    /// Component of self parallel to other.
+    pub fn project_onto(&self, other: Vector<f64>) -> Vector<f64> {
+        other * (self.dot(other) / other.dot(other))
+    }
+
+    /// Component of self perpendicular to other.
+    pub fn reject_from(&self, other: Vector<f64>) -> Vector<f64> {
+        *self - self.project_onto(other)
+    }
+
+    /// The perp-dot product x * other.y - y * other.x, computed as accurately
+    /// as Matrix::determinant_accurate.
+    ///
+    pub fn perp_dot_accurate(&self, other: Vector<f64>) -> f64 {
+        difference_of_products(self.x, other.y, self.y, other.x)
+    }
+
+    /// The mirror image in the line perpendicular to v, as Matrix::householder(v)
+    /// would give, without forming the matrix.
+    ///
+    pub fn reflect(&self, v: Vector<f64>) -> Vector<f64> {
+        *self - self.project_onto(v) * 2.0
+    }
+
+    /// Angle rotating self onto other, in radians within (-pi, pi], counter-clockwise positive.
+    pub fn signed_angle(&self, other: Vector<f64>) -> f64 {
+        self.perp_dot(other).atan2(self.dot(other))
+    }
+}
+
+/// a * b - c * d by Kahan's algorithm: the rounding error of c * d is recovered
+/// exactly with a fused multiply-add and added back.
+///
+fn difference_of_products(a: f64, b: f64, c: f64, d: f64) -> f64 {
+    let cd = c * d;
+    let error = (-c).mul_add(d, cd);
+
+    a.mul_add(b, -cd) + error
+}
+
+impl Matrix<f32> {
+    /// The x solving self * x = b, improved by iterations rounds of iterative
+    /// refinement, or None if this matrix is singular.
+    ///
+    /// Each round computes the residual b - A x in f64 and solves for a
+    /// correction in f32; the solution is accumulated in f64 and rounded once
+    /// at the end.
+    ///
+    pub fn solve_refined(&self, b: Vector<f32>, iterations: usize) -> Option<Vector<f32>> {
+        let det = self.determinant();
+
+        if det == 0.0 {
+            return None;
+        }
+
+        let solve = |r: Vector<f32>| Vector::new(
+            (r.x * self.d - self.b * r.y) / det,
+            (self.a * r.y - r.x * self.c) / det
+        );
+
+        let wide = Matrix::new(self.a as f64, self.b as f64, self.c as f64, self.d as f64);
+        let wide_b = Vector::new(b.x as f64, b.y as f64);
+        let first = solve(b);
+        let mut x = Vector::new(first.x as f64, first.y as f64);
+
+        for _ in 0..iterations {
+            let r = wide_b - wide * x;
+            let correction = solve(Vector::new(r.x as f32, r.y as f32));
+
+            x = x + Vector::new(correction.x as f64, correction.y as f64);
+        }
+
+        Some(Vector::new(x.x as f32, x.y as f32))
+    }
+}
+
+// Integer Methods
+
+impl Matrix<i32> {
+    /// self * rhs, usable in constants; overflow is a compile error there.
+    pub const fn const_mul(&self, rhs: &Matrix<i32>) -> Matrix<i32> {
+        Matrix {
+            a: self.a * rhs.a + self.b * rhs.c,
+            b: self.a * rhs.b + self.b * rhs.d,
+            c: self.c * rhs.a + self.d * rhs.c,
+            d: self.c * rhs.b + self.d * rhs.d
+        }
+    }
+}
+
+impl Matrix<i64> {
+    /// self * rhs, usable in constants; overflow is a compile error there.
+    pub const fn const_mul(&self, rhs: &Matrix<i64>) -> Matrix<i64> {
+        Matrix {
+            a: self.a * rhs.a + self.b * rhs.c,
+            b: self.a * rhs.b + self.b * rhs.d,
+            c: self.c * rhs.a + self.d * rhs.c,
+            d: self.c * rhs.b + self.d * rhs.d
+        }
+    }
+
+    /// The similarity transform P^-1 * self * P, computed exactly, or None
+    /// unless p is unimodular (determinant 1 or -1).
+    ///
+    pub fn conjugate_by_unimodular(&self, p: &Matrix<i64>) -> Option<Matrix<i64>> {
+        let det = p.determinant();
+
+        if det.abs() != 1 {
+            return None;
+        }
+
+        let inverse = Matrix::new(p.d, -p.b, -p.c, p.a) * det;
+
+        Some(inverse * *self * *p)
+    }
+
+    /// Smith normal form: returns (U, D, V) with U * self * V = D, where U and V
+    /// are unimodular and D = diag(d1, d2) with d1, d2 >= 0 and d1 dividing d2.
+    ///
+    pub fn smith_normal_form(&self) -> (Matrix<i64>, Matrix<i64>, Matrix<i64>) {
+        let mut u: Matrix<i64> = Matrix::identity();
+        let mut v: Matrix<i64> = Matrix::identity();
+        let mut d = *self;
+
+        loop {
+            // clear c with a row operation, leaving gcd(a, c) in a; when a already
+            // divides c, plain elimination avoids disturbing b
+            if d.c != 0 {
+                let step = if d.a != 0 && d.c % d.a == 0 {
+                    Matrix::new(1, 0, -d.c / d.a, 1)
+                } else {
+                    let (g, x, y) = extended_gcd(d.a, d.c);
+
+                    Matrix::new(x, y, -d.c / g, d.a / g)
+                };
+
+                u = step * u;
+                d = step * d;
+            }
+
+            // likewise clear b with a column operation
+            if d.b != 0 {
+                let step = if d.a != 0 && d.b % d.a == 0 {
+                    Matrix::new(1, -d.b / d.a, 0, 1)
+                } else {
+                    let (g, x, y) = extended_gcd(d.a, d.b);
+
+                    Matrix::new(x, -d.b / g, y, d.a / g)
+                };
+
+                v = v * step;
+                d = d * step;
+            }
+
+            if d.b != 0 || d.c != 0 {
+                continue;
+            }
+
+            // diagonal; if a does not divide d, add the second row to the first
+            // and go round again, which replaces a by gcd(a, d)
+            let divides = if d.a == 0 { d.d == 0 } else { d.d % d.a == 0 };
+            if divides {
+                break;
+            }
+
+            let step = Matrix::new(1, 1, 0, 1);
+            u = step * u;
+            d = step * d;
+        }
+
+        let signs = Matrix::new(d.a.signum() | 1, 0, 0, d.d.signum() | 1);
+
+        (signs * u, signs * d, v)
+    }
+
+    /// Column-style Hermite normal form: returns (H, U) with self * U = H, where
+    /// U is unimodular and H = [[h1, 0], [h2, h3]] is lower triangular.
+    ///
+    /// For non-singular matrices h1 > 0, h3 > 0 and 0 <= h2 < h3, making H
+    /// unique: the canonical basis of the lattice spanned by the columns.
+    /// Singular matrices still give a triangular H with h1, h3 >= 0.
+    ///
+    pub fn hermite_normal_form(&self) -> (Matrix<i64>, Matrix<i64>) {
+        let mut u = Matrix::identity();
+        let mut h = *self;
+
+        // clear b with a column operation, leaving gcd(a, b) in a
+        if h.b != 0 {
+            let (g, x, y) = extended_gcd(h.a, h.b);
+            let step = Matrix::new(x, -h.b / g, y, h.a / g);
+
+            u = u * step;
+            h = h * step;
+        }
+
+        // make the diagonal non-negative by negating columns
+        let signs = Matrix::new(h.a.signum() | 1, 0, 0, h.d.signum() | 1);
+        u = u * signs;
+        h = h * signs;
+
+        // reduce the off-diagonal entry modulo the diagonal one below it
+        if h.d != 0 {
+            let step = Matrix::new(1, 0, -h.c.div_euclid(h.d), 1);
+
+            u = u * step;
+            h = h * step;
+        }
+
+        (h, u)
+    }
+}
+
+// Boolean Methods
+
+impl Matrix<bool> {
+    /// The product with and for multiplication and or for addition, so for
+    /// adjacency matrices entry (i, j) is whether a walk i -> k -> j exists.
+    ///
+    pub fn boolean_mul(&self, rhs: &Matrix<bool>) -> Matrix<bool> {
+        Matrix::new((self.a && rhs.a) || (self.b && rhs.c),
+                    (self.a && rhs.b) || (self.b && rhs.d),
+                    (self.c && rhs.a) || (self.d && rhs.c),
+                    (self.c && rhs.b) || (self.d && rhs.d))
+    }
+
+    /// The transitive closure of an adjacency matrix, whose entry (i, j) is
+    /// whether a walk of one or more edges leads from i to j.
+    ///
+    /// R is replaced by R or R R until it stops changing, each squaring
+    /// doubling the walk lengths covered.
+    ///
+    pub fn transitive_closure(&self) -> Matrix<bool> {
+        let mut r = *self;
+
+        loop {
+            let s = r.boolean_mul(&r);
+            let next = Matrix::new(r.a || s.a, r.b || s.b, r.c || s.c, r.d || s.d);
+
+            if next == r {
+                return r;
+            }
+            r = next;
+        }
+    }
+}
+
+// Display Methods
+
+impl<T> fmt::Display for Matrix<T> where T: Copy + Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[[{} {}], [{} {}]]", self.a, self.b, self.c, self.d)
+    }
+}
+
+impl<T> fmt::Display for Vector<T> where T: Copy + Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{} {}]^t", self.x, self.y)
+    }
+}
+
+// Operator Methods
+
+/// Implementation of Matrix + Matrix.
+impl<T> Add<Matrix<T>> for Matrix<T> where T: Copy + Add<Output=T> {
+    type Output = Matrix<T>;
+
+    fn add(self, rhs: Matrix<T>) -> Matrix<T> {
+        Matrix::new(
+            self.a + rhs.a,
+            self.b + rhs.b,
+            self.c + rhs.c,
+            self.d + rhs.d
+        )
+    }
+}
+
+/// Implementation of Vector + Vector.
+impl<T> Add<Vector<T>> for Vector<T> where T: Copy + Add<Output=T> {
+    type Output = Vector<T>;
+
+    fn add(self, rhs: Vector<T>) -> Vector<T> {
+        Vector::new(
+            self.x + rhs.x,
+            self.y + rhs.y
+        )
+    }
+}
+
+/// Implementation of Matrix - Matrix.
+impl<T> Sub<Matrix<T>> for Matrix<T> where T: Copy + Sub<Output=T> {
+    type Output = Matrix<T>;
+
+    fn sub(self, rhs: Matrix<T>) -> Matrix<T> {
+        Matrix::new(
+            self.a - rhs.a,
+            self.b - rhs.b,
+            self.c - rhs.c,
+            self.d - rhs.d
+        )
+    }
+}
+
+/// Implementation of Vector - Vector.
+impl<T> Sub<Vector<T>> for Vector<T> where T: Copy + Sub<Output=T> {
+    type Output = Vector<T>;
+
+    fn sub(self, rhs: Vector<T>) -> Vector<T> {
+        Vector::new(
+            self.x - rhs.x,
+            self.y - rhs.y
+        )
+    }
+}
+
+/// Implementation of Matrix * Scalar.
+impl<T, S, V> Mul<S> for Matrix<T> where T: Copy, S: Scalar + Mul<T, Output=V>, V: Copy {
+    type Output = Matrix<V>;
+
+    fn mul(self, rhs: S) -> Matrix<V> {
+        Matrix::new(
+            rhs * self.a,
+            rhs * self.b,
+            rhs * self.c,
+            rhs * self.d
+        )
+    }
+}
+
+/// Implementation of Matrix * Vector, for any entry types whose products
+/// can be summed, such as a dimensionless matrix and a vector of lengths.
+///
+impl<T, U, V> Mul<Vector<U>> for Matrix<T> where T: Copy + Mul<U, Output=V>, U: Copy, V: Copy + Add<Output=V> {
+    type Output = Vector<V>;
+
+    fn mul(self, rhs: Vector<U>) -> Vector<V> {
+        Vector::new(
+            self.a * rhs.x + self.b * rhs.y,
+            self.c * rhs.x + self.d * rhs.y
+        )
+    }
+}
+
+/// Implementation of Matrix * Matrix, for any entry types whose products
+/// can be summed.
+///
+impl<T, U, V> Mul<Matrix<U>> for Matrix<T> where T: Copy + Mul<U, Output=V>, U: Copy, V: Copy + Add<Output=V> {
+    type Output = Matrix<V>;
+
+    fn mul(self, rhs: Matrix<U>) -> Matrix<V> {
+        Matrix::new(
+            self.a * rhs.a + self.b * rhs.c,
+            self.a * rhs.b + self.b * rhs.d,
+            self.c * rhs.a + self.d * rhs.c,
+            self.c * rhs.b + self.d * rhs.d
+        )
+    }
+}
+
+/// Implementation of Vector * Scalar.
+impl<T, S, V> Mul<S> for Vector<T> where T: Copy, S: Scalar + Mul<T, Output=V>, V: Copy {
+    type Output = Vector<V>;
+
+    fn mul(self, rhs: S) -> Vector<V> {
+        Vector::new(
+            rhs * self.x,
+            rhs * self.y
+        )
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use linear_transforms::{rotation, ROTATE_90};
+    use Complex;
+    use Definiteness;
+    use Eigenvalues;
+    use Matrix;
+    use Vector;
+
+    #[test]
+    fn initialize_stuff() {
+        // combining vectors
+        assert_eq!(
+            Matrix::new(1, 2, 3, 4),
+            Matrix::from_vectors(Vector::new(1, 3), Vector::new(2, 4))
+        );
+
+        // splitting matricies
+        assert_eq!(
+            Vector::new(1, 3),
+            Matrix::new(1, 2, 3, 4).left()
+        );
+        assert_eq!(
+            Vector::new(2, 4),
+            Matrix::new(1, 2, 3, 4).right()
+        );
+    }
+
+    #[test]
+    fn manipulate_stuff() {
+        let m: Matrix<u32> = Matrix::new(1, 2, 3, 4);
+        let v: Vector<u32> = Vector::new(5, 6);
+
+        // scaling
+        assert_eq!(Matrix::new(10, 20, 30, 40), m.scale(10));
+        assert_eq!(Vector::new(50, 60),         v.scale(10));
+
+        // adding
+        assert_eq!(Matrix::new(2, 4, 6, 8),     m + m);
+        assert_eq!(Vector::new(10, 12),         v + v);
+
+        // subtracting
+        assert_eq!(Matrix::new(0, 1, 2, 3),     m - Matrix::new(1, 1, 1, 1));
+        assert_eq!(Vector::new(4, 5),           v - Vector::new(1, 1));
+
+        // multiplying
+        assert_eq!(Matrix::new(10, 20, 30, 40), m * 10);
+        assert_eq!(Vector::new(50, 60),         v * 10);
+        assert_eq!(Matrix::new(7, 10, 15, 22),  m * m);
+        assert_eq!(Vector::new(17, 39),         m * v);
+
+        // powers
+        assert_eq!(Matrix::identity(),          m.pow(0));
+        assert_eq!(m,                           m.pow(1));
+        assert_eq!(m * m * m * m * m,           m.pow(5));
+    }
+
+    #[test]
+    fn measure_stuff() {
+        let m: Matrix<f64> = Matrix::new(1.0, 2.0, 3.0, 4.0);
+        let r: Matrix<f64> = Matrix::new(0.6, -0.8, 0.8, 0.6);
+
+        assert_eq!(-2, Matrix::new(1, 2, 3, 4).determinant());
+
+        assert!((30.0f64.sqrt() - m.frobenius_norm()).abs() < 1e-12);
+        assert_eq!(6.0, m.norm_one());
+        assert_eq!(7.0, m.norm_inf());
+        assert!((5.464985704219043 - m.spectral_norm()).abs() < 1e-12);
+
+        // rotations preserve length, so every singular value is 1
+        assert!((1.0 - r.spectral_norm()).abs() < 1e-12);
+        assert!((2.0f64.sqrt() - r.frobenius_norm()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn eigen_stuff() {
+        let m: Matrix<f64> = Matrix::new(2.0, 1.0, 1.0, 2.0);
+
+        assert_eq!(5, Matrix::new(1, 2, 3, 4).trace());
+        assert_eq!(Eigenvalues::RealDistinct(3.0, 1.0), m.eigenvalues());
+        assert_eq!(Eigenvalues::RealRepeated(2.0), Matrix::new(2.0, 0.0, 0.0, 2.0).eigenvalues());
+        assert_eq!(Eigenvalues::RealRepeated(1.0), Matrix::new(1.0, 1.0, 0.0, 1.0).eigenvalues());
+        assert_eq!(
+            Eigenvalues::ComplexConjugate(Complex::new(0.0, 1.0), Complex::new(0.0, -1.0)),
+            Matrix::new(0.0, -1.0, 1.0, 0.0).eigenvalues()
+        );
+        assert_eq!(
+            Eigenvalues::ComplexConjugate(Complex::new(1.0, 2.0), Complex::new(1.0, -2.0)),
+            Matrix::new(1.0, -2.0, 2.0, 1.0).eigenvalues()
+        );
+
+        assert_eq!(Some((3.0, 1.0)), m.eigenvalues().real());
+        assert_eq!(Some((2.0, 2.0)), Matrix::new(2.0, 0.0, 0.0, 2.0).eigenvalues().real());
+        assert_eq!(None, Matrix::new(0.0, -1.0, 1.0, 0.0).eigenvalues().real());
+
+        for &lambda in &[3.0, 1.0] {
+            let v = m.eigenvector(lambda);
+
+            assert!((1.0 - v.length()).abs() < 1e-12);
+            assert!((m * v).distance(v * lambda) < 1e-12);
+        }
+
+        assert_eq!(Vector::new(1.0, 0.0), Matrix::new(2.0, 0.0, 0.0, 2.0).eigenvector(2.0));
+        assert_eq!(5.0, Vector::new(3.0, 4.0).length());
+        assert!(Vector::new(0.6, 0.8).distance(Vector::new(3.0, 4.0).normalize()) < 1e-12);
+    }
+
+    #[test]
+    fn solve_stuff() {
+        let m: Matrix<f64> = Matrix::new(2.0, 1.0, 1.0, 3.0);
+        let b: Vector<f64> = Vector::new(3.0, 5.0);
+
+        assert!((Matrix::new(0.6, -0.2, -0.2, 0.4) - m.inverse().unwrap()).frobenius_norm() < 1e-12);
+        assert!((Matrix::identity() - m * m.inverse().unwrap()).frobenius_norm() < 1e-12);
+        assert_eq!(Some(Vector::new(0.8, 1.4)), m.solve(b));
+        assert!((m * m.solve(b).unwrap()).distance(b) < 1e-12);
+
+        assert_eq!(None, Matrix::new(1.0, 2.0, 2.0, 4.0).inverse());
+        assert_eq!(None, Matrix::new(1.0, 2.0, 2.0, 4.0).solve(b));
+    }
+
+    #[test]
+    fn basis_stuff() {
+        let standard = (Vector::new(1.0, 0.0), Vector::new(0.0, 1.0));
+        let skewed = (Vector::new(1.0, 1.0), Vector::new(-1.0, 1.0));
+        let to_skewed = Matrix::change_of_basis(standard, skewed).unwrap();
+
+        // (2, 0) = 1 * (1, 1) - 1 * (-1, 1)
+        assert_eq!(Vector::new(1.0, -1.0), to_skewed * Vector::new(2.0, 0.0));
+        assert_eq!(Some(Matrix::identity()), Matrix::change_of_basis(skewed, skewed));
+        assert_eq!(None, Matrix::change_of_basis(standard, (Vector::new(1.0, 2.0), Vector::new(2.0, 4.0))));
+
+        // the reflection in y = x is diagonal in a basis of its eigenvectors
+        let swap = Matrix::new(0.0, 1.0, 1.0, 0.0);
+        assert_eq!(Some(Matrix::new(1.0, 0.0, 0.0, -1.0)), swap.in_basis((Vector::new(1.0, 1.0), Vector::new(1.0, -1.0))));
+        assert_eq!(Some(swap), swap.in_basis(standard));
+    }
+
+    #[test]
+    fn conjugate_stuff() {
+        let m: Matrix<f64> = Matrix::new(1.0, 2.0, 3.0, 4.0);
+        let p: Matrix<f64> = Matrix::new(2.0, 1.0, 1.0, 1.0);
+        let similar = m.conjugate_by(&p).unwrap();
+
+        assert!((m.trace() - similar.trace()).abs() < 1e-12);
+        assert!((m.determinant() - similar.determinant()).abs() < 1e-12);
+        assert!((m - similar.conjugate_by(&p.inverse().unwrap()).unwrap()).frobenius_norm() < 1e-12);
+        assert_eq!(None, m.conjugate_by(&Matrix::new(1.0, 2.0, 2.0, 4.0)));
+
+        let n = Matrix::new(1, 2, 3, 4);
+        let q = Matrix::new(2, 1, 1, 1);
+        assert_eq!(Some(Matrix::new(-6, -4, 16, 11)), n.conjugate_by_unimodular(&q));
+        assert_eq!(Some(Matrix::new(4, 3, 2, 1)), n.conjugate_by_unimodular(&Matrix::new(0, 1, 1, 0)));
+        assert_eq!(None, n.conjugate_by_unimodular(&Matrix::new(2, 0, 0, 1)));
+    }
+
+    #[test]
+    fn commutator_stuff() {
+        let x = Matrix::new(0, 1, 1, 0);
+        let z = Matrix::new(1, 0, 0, -1);
+
+        assert_eq!(Matrix::new(0, 2, -2, 0), z.commutator(&x));
+        assert_eq!(Matrix::new(0, 0, 0, 0), z.anticommutator(&x));
+        assert_eq!(Matrix::new(2, 0, 0, 2), x.anticommutator(&x));
+        assert!(!x.commutes_with(&z));
+        assert!(x.commutes_with(&Matrix::new(3, 2, 2, 3)));
+
+        let r = rotation(0.3);
+        assert!(r.approx_commutes_with(&rotation(1.1), 1e-12));
+        assert!(!r.approx_commutes_with(&Matrix::new(1.0, 0.0, 0.0, 2.0), 1e-12));
+    }
+
+    #[test]
+    fn power_iteration_stuff() {
+        let m: Matrix<f64> = Matrix::new(2.0, 1.0, 1.0, 3.0);
+        let estimate = m.power_iteration(1e-10, 100);
+        let (l1, _) = m.eigenvalues().real().unwrap();
+
+        assert!(estimate.converged);
+        assert!(estimate.residual <= 1e-10);
+        assert!((l1 - estimate.eigenvalue).abs() < 1e-10);
+        assert!(estimate.eigenvector.perp_dot(m.eigenvector(l1)).abs() < 1e-9);
+
+        let negative = Matrix::new(-5.0, 0.0, 0.0, 1.0).power_iteration(1e-12, 100);
+        assert!(negative.converged && (negative.eigenvalue + 5.0).abs() < 1e-12);
+
+        // a rotation has no dominant real eigenvalue
+        let stuck = rotation(1.0).power_iteration(1e-12, 50);
+        assert!(!stuck.converged);
+        assert_eq!(50, stuck.iterations);
+
+        let zero = Matrix::new(0.0, 0.0, 0.0, 0.0).power_iteration(1e-12, 50);
+        assert!(zero.converged && zero.eigenvalue == 0.0);
+
+        // [1 1]^t is an eigenvector here, but for the smaller eigenvalue
+        let diagonal = Matrix::new(2.0, -1.0, -1.0, 2.0).power_iteration(1e-12, 100);
+        assert!(diagonal.converged && (diagonal.eigenvalue - 3.0).abs() < 1e-12);
+
+        assert_eq!(1, rotation(1.0).power_iteration(1e-12, 0).iterations);
+    }
+
+    #[test]
+    fn inverse_iteration_stuff() {
+        let m: Matrix<f64> = Matrix::new(2.0, 1.0, 1.0, 3.0);
+        let (l1, l2) = m.eigenvalues().real().unwrap();
+
+        let small = m.inverse_iteration(0.0, 1e-12);
+        assert!(small.converged);
+        assert!((l2 - small.eigenvalue).abs() < 1e-12);
+        assert!(small.eigenvector.perp_dot(m.eigenvector(l2)).abs() < 1e-9);
+
+        let large = m.inverse_iteration(4.0, 1e-12);
+        assert!((l1 - large.eigenvalue).abs() < 1e-12);
+        assert!(large.iterations < m.power_iteration(1e-12, 100).iterations);
+
+        let exact = Matrix::new(1.0, 0.0, 0.0, 2.0).inverse_iteration(2.0, 1e-12);
+        assert!(exact.converged);
+        assert_eq!((0, 2.0), (exact.iterations, exact.eigenvalue));
+    }
+
+    #[test]
+    fn symmetric_eigen_stuff() {
+        let cases = [
+            Matrix::new(2.0, 1.0, 1.0, 3.0),
+            Matrix::new(4.0, 0.0, 0.0, 1.0),
+            Matrix::new(1.0, 0.0, 0.0, 4.0),
+            Matrix::new(1.0, -2.0, -2.0, 1.0),
+            Matrix::new(5.0, 0.0, 0.0, 5.0)
+        ];
+
+        for m in cases.iter() {
+            let (theta, (l1, l2), q) = m.symmetric_eigen();
+            let rebuilt = q * Matrix::new(l1, 0.0, 0.0, l2) * q.transpose();
+
+            assert!((*m - rebuilt).frobenius_norm() < 1e-12);
+            assert!(l1 >= l2);
+            assert!((rotation(theta) - q).frobenius_norm() < 1e-15);
+            assert!((*m * q.left() - q.left() * l1).length() < 1e-12);
+        }
+
+        let (theta, eigenvalues, _) = Matrix::new(1.0, 0.0, 0.0, 4.0).symmetric_eigen();
+        assert_eq!((4.0, 1.0), eigenvalues);
+        assert!((::std::f64::consts::FRAC_PI_2 - theta).abs() < 1e-15);
+    }
+
+    #[test]
+    fn pivoted_solve_stuff() {
+        let m: Matrix<f64> = Matrix::new(1.0, 2.0, 3.0, 4.0);
+        let (x, residual) = m.solve_pivoted(Vector::new(5.0, 6.0)).unwrap();
+
+        assert!((Vector::new(-4.0, 4.5) - x).length() < 1e-12);
+        assert!(residual < 1e-15);
+
+        // a zero leading entry needs the rows swapped
+        let (x, _) = Matrix::new(0.0, 1.0, 1.0, 0.0).solve_pivoted(Vector::new(2.0, 3.0)).unwrap();
+        assert_eq!(Vector::new(3.0, 2.0), x);
+
+        let tiny = Matrix::new(1e-20, 1.0, 1.0, 1.0);
+        let (x, residual) = tiny.solve_pivoted(Vector::new(1.0, 2.0)).unwrap();
+        assert!((Vector::new(1.0, 1.0) - x).length() < 1e-12);
+        assert!(residual < 1e-15);
+
+        assert_eq!(None, Matrix::new(1.0, 2.0, 2.0, 4.0).solve_pivoted(Vector::new(1.0, 1.0)));
+        assert_eq!(Some((Vector::new(0.0, 0.0), 0.0)), m.solve_pivoted(Vector::new(0.0, 0.0)));
+    }
+
+    #[test]
+    fn refined_solve_stuff() {
+        // condition number around 6e4, so plain f32 solving loses most digits
+        let m: Matrix<f32> = Matrix::new(3.0, 1.9999, 1.5, 1.0);
+        let b: Vector<f32> = Vector::new(1.0, 1.0);
+
+        // the exact solution for the rounded f32 entries
+        let wide = Matrix::new(m.a as f64, m.b as f64, m.c as f64, m.d as f64);
+        let exact = wide.solve(Vector::new(b.x as f64, b.y as f64)).unwrap();
+        let error = |x: Vector<f32>| Vector::new(x.x as f64 - exact.x, x.y as f64 - exact.y).length();
+
+        let plain = m.solve_refined(b, 0).unwrap();
+        let refined = m.solve_refined(b, 3).unwrap();
+
+        assert!(error(plain) > 1.0);
+        assert!(error(refined) < 1e-3);
+        assert_eq!(None, Matrix::new(1.0f32, 2.0, 2.0, 4.0).solve_refined(b, 3));
+    }
+
+    #[test]
+    fn accurate_determinant_stuff() {
+        let epsilon = 2.0f64.powi(-30);
+        let m = Matrix::new(1.0 + epsilon, 1.0, 1.0, 1.0 - epsilon);
+
+        // (1 + e)(1 - e) - 1 rounds to zero when computed naively
+        assert_eq!(0.0, m.determinant());
+        assert_eq!(-epsilon * epsilon, m.determinant_accurate());
+
+        let u = Vector::new(1.0 + epsilon, 1.0);
+        assert_eq!(-epsilon * epsilon, u.perp_dot_accurate(Vector::new(1.0, 1.0 - epsilon)));
+
+        assert_eq!(-2.0, Matrix::new(1.0, 2.0, 3.0, 4.0).determinant_accurate());
+    }
+
+    #[test]
+    fn normal_matrix_stuff() {
+        let stretch: Matrix<f64> = Matrix::new(2.0, 0.0, 0.0, 1.0);
+        let tangent = Vector::new(1.0, -1.0);
+        let normal = Vector::new(1.0, 1.0);
+
+        // under non-uniform scaling the normal must turn the other way to stay perpendicular
+        let n = stretch.normal_matrix().unwrap() * normal;
+        assert_eq!(0.0, n.dot(stretch * tangent));
+        assert!((stretch * normal).dot(stretch * tangent) != 0.0);
+
+        assert!((rotation(0.6) - rotation(0.6).normal_matrix().unwrap()).frobenius_norm() < 1e-12);
+        assert_eq!(None, Matrix::new(1.0, 2.0, 2.0, 4.0).normal_matrix());
+    }
+
+    #[test]
+    fn units_stuff() {
+        use std::ops::{Add, Mul};
+
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        struct Meters(f64);
+
+        impl Add<Meters> for Meters {
+            type Output = Meters;
+
+            fn add(self, rhs: Meters) -> Meters {
+                Meters(self.0 + rhs.0)
+            }
+        }
+
+        impl Mul<f64> for Meters {
+            type Output = Meters;
+
+            fn mul(self, rhs: f64) -> Meters {
+                Meters(self.0 * rhs)
+            }
+        }
+
+        impl Mul<Meters> for f64 {
+            type Output = Meters;
+
+            fn mul(self, rhs: Meters) -> Meters {
+                Meters(self * rhs.0)
+            }
+        }
+
+        let turn = Matrix::new(0.0, -1.0, 1.0, 0.0);
+        let lengths = Matrix::new(Meters(1.0), Meters(2.0), Meters(3.0), Meters(4.0));
+
+        assert_eq!(Vector::new(Meters(-2.0), Meters(1.0)), turn * Vector::new(Meters(1.0), Meters(2.0)));
+        assert_eq!(Matrix::new(Meters(2.0), Meters(-1.0), Meters(4.0), Meters(-3.0)), lengths * turn);
+        assert_eq!(Matrix::new(Meters(-3.0), Meters(-4.0), Meters(1.0), Meters(2.0)), turn * lengths);
+        assert_eq!(Vector::new(Meters(2.0), Meters(6.0)), lengths.left() * 2.0);
+        assert_eq!(Matrix::new(Meters(0.5), Meters(1.0), Meters(1.5), Meters(2.0)), lengths * 0.5);
+    }
+
+    #[test]
+    fn boolean_stuff() {
+        // 0 -> 1 -> 0, with no loops
+        let swap = Matrix::new(false, true, true, false);
+        assert_eq!(Matrix::identity(), swap.boolean_mul(&swap));
+        assert_eq!(Matrix::new(true, true, true, true), swap.transitive_closure());
+
+        // 0 -> 1 only
+        let edge = Matrix::new(false, true, false, false);
+        assert_eq!(Matrix::new(false, false, false, false), edge.boolean_mul(&edge));
+        assert_eq!(edge, edge.transitive_closure());
+
+        let reflexive = Matrix::new(true, true, false, true);
+        assert_eq!(reflexive, reflexive.transitive_closure());
+    }
+
+    #[test]
+    fn mul_add_stuff() {
+        let a = Matrix::new(1.0, 2.0, 3.0, 4.0);
+        let b = Matrix::new(0.5, -1.0, 2.0, 0.25);
+        let c = Matrix::new(1.0, 1.0, -1.0, 0.0);
+
+        assert_eq!(a * b + c, a.mul_add(&b, &c));
+        assert_eq!(a * Vector::new(2.0, -1.0) + Vector::new(0.5, 0.5), a.mul_add_vector(Vector::new(2.0, -1.0), Vector::new(0.5, 0.5)));
+
+        // x^2 = 1 + 2^-26 + 2^-54, whose last term the separate product drops
+        let x = 1.0 + 2f64.powi(-27);
+        let square = Matrix::new(x, 0.0, 0.0, x);
+        let shift = Matrix::new(-(1.0 + 2f64.powi(-26)), 0.0, 0.0, 0.0);
+
+        assert_eq!(0.0, (square * square + shift).a);
+        assert_eq!(2f64.powi(-54), square.mul_add(&square, &shift).a);
+        assert_eq!(2f64.powi(-54), square.mul_add_vector(Vector::new(x, 0.0), Vector::new(shift.a, 0.0)).x);
+    }
+
+    #[test]
+    fn layout_stuff() {
+        use std::mem::size_of;
+        use {Affine2, Matrix3, Matrix4, Point2, Vector3};
+
+        let m = Matrix::new(1.0f32, 2.0, 3.0, 4.0);
+        let base = &m as *const Matrix<f32> as usize;
+        let offsets = [&m.a, &m.b, &m.c, &m.d].iter().map(|&e| e as *const f32 as usize - base).collect::<Vec<_>>();
+
+        assert_eq!(vec![0, 4, 8, 12], offsets);
+        assert_eq!(8, size_of::<Vector<f32>>());
+        assert_eq!(8, size_of::<Point2<f32>>());
+        assert_eq!(12, size_of::<Vector3<f32>>());
+        assert_eq!(16, size_of::<Matrix<f32>>());
+        assert_eq!(24, size_of::<Affine2<f32>>());
+        assert_eq!(36, size_of::<Matrix3<f32>>());
+        assert_eq!(64, size_of::<Matrix4<f32>>());
+        assert_eq!(16, size_of::<Complex<f64>>());
+    }
+
+    #[test]
+    fn smith_stuff() {
+        let cases = [
+            Matrix::new(2, 4, 6, 8),
+            Matrix::new(2, 0, 0, 3),
+            Matrix::new(0, 0, 0, 5),
+            Matrix::new(0, 0, 0, 0),
+            Matrix::new(-4, 6, 10, -14),
+            Matrix::new(1, 2, 3, 4),
+            Matrix::new(6, 0, 0, -4)
+        ];
+        let expected = [
+            Matrix::new(2, 0, 0, 4),
+            Matrix::new(1, 0, 0, 6),
+            Matrix::new(5, 0, 0, 0),
+            Matrix::new(0, 0, 0, 0),
+            Matrix::new(2, 0, 0, 2),
+            Matrix::new(1, 0, 0, 2),
+            Matrix::new(2, 0, 0, 12)
+        ];
+
+        for (m, e) in cases.iter().zip(expected.iter()) {
+            let (u, d, v) = m.smith_normal_form();
+
+            assert_eq!(*e, d);
+            assert_eq!(d, u * *m * v);
+            assert_eq!(1, u.determinant().abs());
+            assert_eq!(1, v.determinant().abs());
+        }
+    }
+
+    #[test]
+    fn hermite_stuff() {
+        let cases = [
+            (Matrix::new(2, 4, 6, 8),   Matrix::new(2, 0, 2, 4)),
+            (Matrix::new(3, 0, 7, 5),   Matrix::new(3, 0, 2, 5)),
+            (Matrix::new(-1, 0, 0, -1), Matrix::new(1, 0, 0, 1)),
+            (Matrix::new(4, 6, 1, 1),   Matrix::new(2, 0, 0, 1)),
+            (Matrix::new(1, 2, 2, 4),   Matrix::new(1, 0, 2, 0))
+        ];
+
+        for &(m, expected) in cases.iter() {
+            let (h, u) = m.hermite_normal_form();
+
+            assert_eq!(expected, h);
+            assert_eq!(h, m * u);
+            assert_eq!(1, u.determinant().abs());
+        }
+
+        // bases of the same lattice share a normal form
+        let basis = Matrix::new(3, 1, 1, 4);
+        let other = basis * Matrix::new(2, 1, 1, 1);
+        assert_eq!(basis.hermite_normal_form().0, other.hermite_normal_form().0);
+    }
+
+    #[test]
+    fn diagonalize_stuff() {
+        let m: Matrix<f64> = Matrix::new(4.0, 1.0, 2.0, 3.0);
+        let (p, d) = m.diagonalize().unwrap();
+
+        assert_eq!(Matrix::new(5.0, 0.0, 0.0, 2.0), d);
+        assert!((m - p * d * p.inverse().unwrap()).frobenius_norm() < 1e-12);
+
+        // closed-form powers
+        let cube = p * d.pow(3) * p.inverse().unwrap();
+        assert!((m.pow(3) - cube).frobenius_norm() < 1e-9);
+
+        assert_eq!(
+            Some((Matrix::identity(), Matrix::new(3.0, 0.0, 0.0, 3.0))),
+            Matrix::new(3.0, 0.0, 0.0, 3.0).diagonalize()
+        );
+        assert!(!Matrix::new(1.0, 1.0, 0.0, 1.0).is_diagonalizable());
+        assert!(!Matrix::new(0.0, -1.0, 1.0, 0.0).is_diagonalizable());
+        assert!(m.is_diagonalizable());
+    }
+
+    #[test]
+    fn jordan_stuff() {
+        let defective: Matrix<f64> = Matrix::new(3.0, 1.0, -1.0, 1.0);
+        let (p, j) = defective.jordan_form().unwrap();
+
+        assert_eq!(Matrix::new(2.0, 1.0, 0.0, 2.0), j);
+        assert!((defective - p * j * p.inverse().unwrap()).frobenius_norm() < 1e-12);
+
+        // the first column of P is an eigenvector, the second a generalized one
+        assert_eq!(p.left() * 2.0, defective * p.left());
+        assert_eq!(p.left(), (defective - Matrix::new(2.0, 0.0, 0.0, 2.0)) * p.right());
+
+        let shear = Matrix::new(1.0, 0.0, 5.0, 1.0);
+        let (p, j) = shear.jordan_form().unwrap();
+        assert_eq!(Matrix::new(1.0, 1.0, 0.0, 1.0), j);
+        assert_eq!(shear, p * j * p.inverse().unwrap());
+
+        let m = Matrix::new(4.0, 1.0, 2.0, 3.0);
+        assert_eq!(m.diagonalize(), m.jordan_form());
+        assert_eq!(None, Matrix::new(0.0, -1.0, 1.0, 0.0).jordan_form());
+    }
+
+    #[test]
+    fn schur_stuff() {
+        let real: Matrix<f64> = Matrix::new(1.0, 2.0, 3.0, 4.0);
+        let (q, t) = real.schur();
+        let (l1, l2) = real.eigenvalues().real().unwrap();
+
+        assert!((real - q * t * q.transpose()).frobenius_norm() < 1e-12);
+        assert!((Matrix::identity() - q.transpose() * q).frobenius_norm() < 1e-12);
+        assert_eq!(0.0, t.c);
+        assert!((l1 - t.a).abs() < 1e-12 && (l2 - t.d).abs() < 1e-12);
+
+        let spiral: Matrix<f64> = Matrix::new(1.0, -5.0, 2.0, 3.0);
+        let (q, t) = spiral.schur();
+
+        assert!((spiral - q * t * q.transpose()).frobenius_norm() < 1e-12);
+        assert!((1.0 - q.determinant()).abs() < 1e-12);
+        assert!((t.a - t.d).abs() < 1e-12);
+        assert!((2.0 - t.a).abs() < 1e-12);
+
+        // eigenvalues 2 +- 3i
+        assert!((-9.0 - t.b * t.c).abs() < 1e-12);
+    }
+
+    #[test]
+    fn stability_stuff() {
+        assert_eq!(5.0, Matrix::new(1.0, 2.0, 4.0, 3.0).spectral_radius());
+        assert_eq!(3.0, Matrix::new(-3.0, 1.0, 0.0, -3.0).spectral_radius());
+        assert!((0.5 - (rotation(1.0) * 0.5).spectral_radius()).abs() < 1e-12);
+
+        let contracting = Matrix::new(0.5, 0.4, 0.1, 0.2);
+        assert!(contracting.converges_under_iteration());
+        assert!(contracting.pow(200).frobenius_norm() < 1e-12);
+
+        // norms above 1 do not prevent convergence
+        let sheared = Matrix::new(0.5, 10.0, 0.0, 0.5);
+        assert!(sheared.frobenius_norm() > 1.0);
+        assert!(sheared.converges_under_iteration());
+
+        assert!(!rotation(1.0).converges_under_iteration());
+        assert!(!Matrix::new(1.1, 0.0, 0.0, 0.1).converges_under_iteration());
+    }
+
+    #[test]
+    fn form_stuff() {
+        let m: Matrix<i32> = Matrix::new(1, 2, 3, 4);
+        let u: Vector<i32> = Vector::new(1, 0);
+        let v: Vector<i32> = Vector::new(0, 1);
+
+        assert_eq!(2,  m.bilinear_form(u, v));
+        assert_eq!(3,  m.bilinear_form(v, u));
+        assert_eq!(1,  m.quadratic_form(u));
+        assert_eq!(10, m.quadratic_form(u + v));
+
+        // the identity gives the dot product
+        assert_eq!(Vector::new(3, 4).dot(Vector::new(5, 6)),
+                   Matrix::identity().bilinear_form(Vector::new(3, 4), Vector::new(5, 6)));
+    }
+
+    #[test]
+    fn definiteness_stuff() {
+        assert_eq!(Definiteness::PositiveDefinite,     Matrix::new(2, 1, 1, 2).definiteness());
+        assert_eq!(Definiteness::PositiveSemiDefinite, Matrix::new(1, 1, 1, 1).definiteness());
+        assert_eq!(Definiteness::PositiveSemiDefinite, Matrix::new(0, 0, 0, 3).definiteness());
+        assert_eq!(Definiteness::Indefinite,           Matrix::new(1, 2, 2, 1).definiteness());
+        assert_eq!(Definiteness::Indefinite,           Matrix::new(0, 1, 1, 0).definiteness());
+        assert_eq!(Definiteness::NegativeSemiDefinite, Matrix::new(0, 0, 0, -3).definiteness());
+        assert_eq!(Definiteness::NegativeDefinite,     Matrix::new(-2.0, 1.0, 1.0, -2.0).definiteness());
+        assert_eq!(Definiteness::PositiveSemiDefinite, Matrix::new(0, 0, 0, 0).definiteness());
+    }
+
+    #[test]
+    fn function_stuff() {
+        let spd: Matrix<f64> = Matrix::new(5.0, 4.0, 4.0, 5.0);
+        let defective: Matrix<f64> = Matrix::new(3.0, 1.0, -1.0, 1.0);
+
+        let root = spd.sqrt().unwrap();
+        assert!((Matrix::new(2.0, 1.0, 1.0, 2.0) - root).frobenius_norm() < 1e-12);
+        assert!((spd - spd.exp().ln().unwrap()).frobenius_norm() < 1e-9);
+        assert!((spd.pow(3) - spd.powf(3.0).unwrap()).frobenius_norm() < 1e-9);
+
+        let defective_root = defective.sqrt().unwrap();
+        assert!((defective - defective_root * defective_root).frobenius_norm() < 1e-9);
+        assert!((defective.exp() - defective.apply_fn(f64::exp).unwrap()).frobenius_norm() < 1e-9);
+
+        assert_eq!(Some(Matrix::new(9.0, 0.0, 0.0, 9.0)), Matrix::new(3.0, 0.0, 0.0, 3.0).apply_fn(|x| x * x));
+        assert_eq!(None, rotation(1.0).apply_fn(f64::exp));
+
+        // a repeated eigenvalue near zero keeps the difference in ln's domain
+        let tiny: Matrix<f64> = Matrix::new(1e-6, 1.0, 0.0, 1e-6);
+        let exact = Matrix::new(1e-6f64.ln(), 1e6, 0.0, 1e-6f64.ln());
+        assert!((exact - tiny.apply_fn(f64::ln).unwrap()).frobenius_norm() < 1e-3);
+        assert_eq!(Some(exact), tiny.ln());
+        assert!((Matrix::new(1e-3, 500.0, 0.0, 1e-3) - tiny.apply_fn(f64::sqrt).unwrap()).frobenius_norm() < 1e-6);
+        assert_eq!(Some(Matrix::new(1e-3, 500.0, 0.0, 1e-3)), tiny.sqrt());
+
+        // the exponential of a rotation generator is a rotation
+        assert!((rotation(0.7) - Matrix::new(0.0, -0.7, 0.7, 0.0).exp()).frobenius_norm() < 1e-12);
+        assert!((spd.exp() - spd.apply_fn(f64::exp).unwrap()).frobenius_norm() < 1e-9);
+        assert_eq!(Matrix::new(1.0, 1.0, 0.0, 1.0), Matrix::new(0.0, 1.0, 0.0, 0.0).exp());
+    }
+
+    #[test]
+    fn cayley_stuff() {
+        let skew: Matrix<f64> = Matrix::new(0.0, -0.5, 0.5, 0.0);
+        let q = skew.cayley().unwrap();
+
+        assert!((rotation(2.0 * 0.5f64.atan()) - q).frobenius_norm() < 1e-12);
+        assert!((skew - q.inverse_cayley().unwrap()).frobenius_norm() < 1e-12);
+
+        let m: Matrix<f64> = Matrix::new(1.0, 2.0, 3.0, 4.0);
+        assert!((m - m.cayley().unwrap().inverse_cayley().unwrap()).frobenius_norm() < 1e-12);
+
+        assert_eq!(None, Matrix::new(1.0, 0.0, 0.0, 2.0).cayley());
+        assert_eq!(None, Matrix::new(-1.0, 0.0, 0.0, -1.0).inverse_cayley());
+    }
+
+    #[test]
+    fn givens_stuff() {
+        let (g, c, s) = Matrix::givens(3.0, 4.0);
+
+        assert!((Vector::new(5.0, 0.0) - g * Vector::new(3.0, 4.0)).length() < 1e-12);
+        assert_eq!((0.6, 0.8), (c, s));
+        assert!((1.0 - g.determinant()).abs() < 1e-12);
+
+        let (g, _, _) = Matrix::givens(-1.0, 1e-3);
+        let v = g * Vector::new(-1.0, 1e-3);
+        assert!(v.x > 0.0 && v.y.abs() < 1e-15);
+
+        assert_eq!(Matrix::identity(), Matrix::givens(0.0, 0.0).0);
+    }
+
+    #[test]
+    fn householder_stuff() {
+        let v = Vector::new(1.0, 1.0);
+        let h = Matrix::householder(v);
+
+        assert!((Matrix::new(0.0, -1.0, -1.0, 0.0) - h).frobenius_norm() < 1e-12);
+        assert!((Matrix::identity() - h * h).frobenius_norm() < 1e-12);
+        assert!((-1.0 - h.determinant()).abs() < 1e-12);
+
+        let x = Vector::new(3.0, -2.0);
+        assert!((h * x - x.reflect(v)).length() < 1e-12);
+        assert!((Vector::new(2.0, -3.0) - x.reflect(v)).length() < 1e-12);
+        assert_eq!(Vector::new(-3.0, -2.0), x.reflect(Vector::new(2.0, 0.0)));
+    }
+
+    #[test]
+    fn bytes_stuff() {
+        let m = Matrix::new(1i32, -2, 3, 0x0102_0304);
+        let bytes = m.to_bytes();
+
+        assert_eq!(vec![1, 0, 0, 0, 0xfe, 0xff, 0xff, 0xff, 3, 0, 0, 0, 4, 3, 2, 1], bytes);
+        assert_eq!(Some(m), Matrix::from_bytes(&bytes));
+        assert_eq!(None, Matrix::<i32>::from_bytes(&bytes[..15]));
+
+        let v = Vector::new(0.1f64, -1e300);
+        assert_eq!(Some(v), Vector::from_bytes(&v.to_bytes()));
+        assert_eq!(None, Vector::<f64>::from_bytes(&[]));
+
+        // the same bytes as bincode, and for floats postcard
+        let f = Matrix::new(0.1f64, -2.5, 1e300, f64::MIN_POSITIVE);
+        assert_eq!(bincode::serialize(&[m.a, m.b, m.c, m.d]).unwrap(), bytes);
+        assert_eq!(bincode::serialize(&[f.a, f.b, f.c, f.d]).unwrap(), f.to_bytes());
+        assert_eq!(postcard::to_allocvec(&[f.a, f.b, f.c, f.d]).unwrap(), f.to_bytes());
+        assert_eq!(postcard::to_allocvec(&[0.5f32, -1.0]).unwrap(), Vector::new(0.5f32, -1.0).to_bytes());
+    }
+
+    #[test]
+    fn svd_stuff() {
+        let cases = [
+            Matrix::new(1.0, 2.0, 3.0, 4.0),
+            Matrix::new(2.0, 0.0, 0.0, -3.0),
+            Matrix::new(0.0, -1.0, 1.0, 0.0),
+            Matrix::new(1.0, 1.0, 1.0, 1.0),
+            Matrix::new(0.0, 0.0, 0.0, 0.0)
+        ];
+
+        for m in cases.iter() {
+            let (u, (s1, s2), v) = m.svd();
+            let rebuilt = u * Matrix::new(s1, 0.0, 0.0, s2) * v.transpose();
+
+            assert!((*m - rebuilt).frobenius_norm() < 1e-12);
+            assert!(s1 >= s2 && s2 >= 0.0);
+            assert!((1.0 - u.determinant()).abs() < 1e-12);
+            assert!((Matrix::identity() - v.transpose() * v).frobenius_norm() < 1e-12);
+            assert!((m.spectral_norm() - s1).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn angle_stuff() {
+        use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+        let i: Vector<f64> = Vector::new(1.0, 0.0);
+        let j: Vector<f64> = Vector::new(0.0, 1.0);
+        let d: Vector<f64> = Vector::new(1.0, 1.0);
+
+        assert_eq!(11, Vector::new(1, 2).dot(Vector::new(3, 4)));
+        assert_eq!(-2, Vector::new(1, 2).perp_dot(Vector::new(3, 4)));
+        assert_eq!(0.0, d.perp().dot(d));
+        assert_eq!(d.perp() * -1.0, d.perp_cw());
+
+        assert!((FRAC_PI_4 - i.angle(d)).abs() < 1e-12);
+        assert!((FRAC_PI_2 - j.angle(i)).abs() < 1e-12);
+        assert!((PI - i.angle(i * -1.0)).abs() < 1e-12);
+
+        assert!((FRAC_PI_2 - i.signed_angle(j)).abs() < 1e-12);
+        assert!((-FRAC_PI_2 - j.signed_angle(i)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn decompose_stuff() {
+        let v: Vector<f64> = Vector::new(3.0, 4.0);
+        let d: Vector<f64> = Vector::new(2.0, 0.0);
+
+        assert_eq!(Vector::new(3.0, 0.0), v.project_onto(d));
+        assert_eq!(Vector::new(0.0, 4.0), v.reject_from(d));
+        assert_eq!(v, v.project_onto(d) + v.reject_from(d));
+
+        assert_eq!(Vector::new(1.0, 1.0), Vector::new(2.0, 0.0).project_onto(Vector::new(1.0, 1.0)));
+    }
+
+    #[test]
+    fn distance_stuff() {
+        assert_eq!(25, Vector::new(1, 2).distance_squared(Vector::new(4, 6)));
+        assert_eq!(0,  Vector::new(1, 2).distance_squared(Vector::new(1, 2)));
+        assert_eq!(5.0, Vector::new(1.0, 2.0).distance(Vector::new(4.0, 6.0)));
+        assert_eq!(5.0, Vector::new(4.0, 6.0).distance(Vector::new(1.0, 2.0)));
+    }
+
+    #[test]
+    fn orbit_stuff() {
+        let fib = Matrix::new(1, 1, 1, 0);
+        let firsts: Vec<i32> = fib.orbit(Vector::new(1, 0)).map(|v| v.x).take(8).collect();
+
+        assert_eq!(vec![1, 1, 2, 3, 5, 8, 13, 21], firsts);
+        assert_eq!(Some(Vector::new(0, 1)), ROTATE_90.orbit(Vector::new(1, 0)).nth(5));
+
+        let halving = Matrix::new(0.5, 0.0, 0.0, 0.5);
+        let steps = halving.orbit(Vector::new(1.0, 1.0)).take_while(|v| v.length() > 1e-3).count();
+        assert_eq!(11, steps);
+    }
+
+    #[test]
+    fn complex_stuff() {
+        let z = Complex::new(1, 2);
+        let w = Complex::new(3, -1);
+
+        assert_eq!(Matrix::new(1, -2, 2, 1), Matrix::from_complex(z));
+        assert_eq!(Matrix::from_complex(z * w), Matrix::from_complex(z) * Matrix::from_complex(w));
+        assert_eq!(Some(z), Matrix::from_complex(z).to_complex());
+        assert_eq!(None, Matrix::new(1, 2, 2, 1).to_complex());
+
+        let unit = Complex::from_polar(1.0, 0.3);
+        assert_eq!(rotation(0.3), Matrix::from_complex(unit));
+    }
+
+    #[test]
+    fn kronecker_stuff() {
+        let i: Matrix<i32> = Matrix::new(1, 0, 0, 1);
+        let x: Matrix<i32> = Matrix::new(0, 1, 1, 0);
+        let z: Matrix<i32> = Matrix::new(1, 0, 0, -1);
+
+        assert_eq!(
+            Matrix::new(x, Matrix::new(0, 0, 0, 0), Matrix::new(0, 0, 0, 0), x),
+            i.kronecker(&x)
+        );
+        assert_eq!(
+            Matrix::new(z, z * 2, z * 3, z * 4),
+            Matrix::new(1, 2, 3, 4).kronecker(&z)
+        );
+
+        // block multiplication obeys the mixed-product property
+        assert_eq!(
+            (x * z).kronecker(&(z * x)),
+            x.kronecker(&z) * z.kronecker(&x)
+        );
     }
 }
@@ -1,16 +1,55 @@
+use std::ops::Mul;
+
 use Matrix;
+use Matrix3;
+use UnitVector;
 use Vector;
+use Vector3;
+
+pub const IDENTITY:   Matrix<i32> = Matrix { a: 1,  b: 0,  c: 0,  d: 1  };
+
+pub const ROTATE_90:  Matrix<i32> = Matrix { a: 0,  b: -1, c: 1,  d: 0  };
+pub const ROTATE_180: Matrix<i32> = Matrix { a: -1, b: 0,  c: 0,  d: -1 };
+pub const ROTATE_270: Matrix<i32> = Matrix { a: 0,  b: 1,  c: -1, d: 0  };
+
+pub const FLIP_X:     Matrix<i32> = Matrix { a: -1, b: 0,  c: 0,  d: 1  };
+pub const FLIP_Y:     Matrix<i32> = Matrix { a: 1, b: 0,   c: 0,  d: -1 };
 
-const IDENTITY:   Matrix<i32> = Matrix { a: 1,  b: 0,  c: 0,  d: 1  };
+/// The eight symmetries of the square: the four rotations, then each of them
+/// after FLIP_X.
+///
+pub const D4: [Matrix<i32>; 8] = [
+    IDENTITY,
+    ROTATE_90,
+    ROTATE_180,
+    ROTATE_270,
+    FLIP_X,
+    ROTATE_90.const_mul(&FLIP_X),
+    ROTATE_180.const_mul(&FLIP_X),
+    ROTATE_270.const_mul(&FLIP_X)
+];
 
-const ROTATE_90:  Matrix<i32> = Matrix { a: 0,  b: -1, c: 1,  d: 0  };
-const ROTATE_180: Matrix<i32> = Matrix { a: -1, b: 0,  c: 0,  d: -1 };
-const ROTATE_270: Matrix<i32> = Matrix { a: 0,  b: 1,  c: -1, d: 0  };
+/// The multiplication table of D4, D4_PRODUCTS[i][j] = D4[i] * D4[j],
+/// computed at compile time.
+///
+pub const D4_PRODUCTS: [[Matrix<i32>; 8]; 8] = {
+    let mut table = [[IDENTITY; 8]; 8];
+    let mut i = 0;
 
-const FLIP_X:     Matrix<i32> = Matrix { a: -1, b: 0,  c: 0,  d: 1  };
-const FLIP_Y:     Matrix<i32> = Matrix { a: 1, b: 0,   c: 0,  d: -1 };
+    while i < 8 {
+        let mut j = 0;
 
-fn rotation(radians: f64) -> Matrix<f64> {
+        while j < 8 {
+            table[i][j] = D4[i].const_mul(&D4[j]);
+            j += 1;
+        }
+        i += 1;
+    }
+
+    table
+};
+
+pub fn rotation(radians: f64) -> Matrix<f64> {
     Matrix::new(
         radians.cos(),
         -radians.sin(),
@@ -19,6 +58,111 @@ fn rotation(radians: f64) -> Matrix<f64> {
     )
 }
 
+/// Rotation by radians about the x axis, counter-clockwise looking down it.
+pub fn rotation_x(radians: f64) -> Matrix3<f64> {
+    let (c, s) = (radians.cos(), radians.sin());
+
+    Matrix3::new([
+        [1.0, 0.0, 0.0],
+        [0.0, c,   -s ],
+        [0.0, s,   c  ]
+    ])
+}
+
+/// Rotation by radians about the y axis, counter-clockwise looking down it.
+pub fn rotation_y(radians: f64) -> Matrix3<f64> {
+    let (c, s) = (radians.cos(), radians.sin());
+
+    Matrix3::new([
+        [c,   0.0, s  ],
+        [0.0, 1.0, 0.0],
+        [-s,  0.0, c  ]
+    ])
+}
+
+/// Rotation by radians about the z axis, which acts on x and y as rotation does.
+pub fn rotation_z(radians: f64) -> Matrix3<f64> {
+    let (c, s) = (radians.cos(), radians.sin());
+
+    Matrix3::new([
+        [c,   -s,  0.0],
+        [s,   c,   0.0],
+        [0.0, 0.0, 1.0]
+    ])
+}
+
+/// Rotation by radians about axis, which need not be a unit vector, by
+/// Rodrigues' formula.
+///
+pub fn rotation_axis_angle(axis: Vector3<f64>, radians: f64) -> Matrix3<f64> {
+    let u = axis.normalize();
+    let (x, y, z) = (u.x(), u.y(), u.z());
+    let (c, s) = (radians.cos(), radians.sin());
+    let t = 1.0 - c;
+
+    Matrix3::new([
+        [c + x * x * t,     x * y * t - z * s, x * z * t + y * s],
+        [y * x * t + z * s, c + y * y * t,     y * z * t - x * s],
+        [z * x * t - y * s, z * y * t + x * s, c + z * z * t    ]
+    ])
+}
+
+/// Represents a rotation about the origin by an angle in radians, counter-clockwise.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Rotation2 {
+    angle: f64
+}
+
+impl Rotation2 {
+    pub fn new(radians: f64) -> Rotation2 {
+        Rotation2 {
+            angle: radians
+        }
+    }
+
+    /// The rotation taking the x axis to u.
+    pub fn from_direction(u: UnitVector<f64>) -> Rotation2 {
+        Rotation2::new(u.angle())
+    }
+
+    /// The rotation represented by m, assuming m is a rotation matrix.
+    pub fn from_matrix(m: &Matrix<f64>) -> Rotation2 {
+        Rotation2::new(m.c.atan2(m.a))
+    }
+
+    pub fn angle(&self) -> f64 {
+        self.angle
+    }
+
+    pub fn inverse(&self) -> Rotation2 {
+        Rotation2::new(-self.angle)
+    }
+
+    pub fn matrix(&self) -> Matrix<f64> {
+        rotation(self.angle)
+    }
+}
+
+/// Implementation of Rotation2 * Rotation2.
+impl Mul<Rotation2> for Rotation2 {
+    type Output = Rotation2;
+
+    // composing rotations adds their angles
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: Rotation2) -> Rotation2 {
+        Rotation2::new(self.angle + rhs.angle)
+    }
+}
+
+/// Implementation of Rotation2 * Vector.
+impl Mul<Vector<f64>> for Rotation2 {
+    type Output = Vector<f64>;
+
+    fn mul(self, rhs: Vector<f64>) -> Vector<f64> {
+        self.matrix() * rhs
+    }
+}
+
 #[test]
 fn rotating() {
     let v: Vector<i32> = Vector::new(1, 2);
@@ -29,6 +173,9 @@ fn rotating() {
     assert_eq!(Vector::new( 2, -1), ROTATE_270 * v);
 
     assert_eq!(IDENTITY, ROTATE_90 * ROTATE_270);
+
+    assert_eq!(v.perp(),            ROTATE_90  * v);
+    assert_eq!(v.perp_cw(),         ROTATE_270 * v);
 }
 
 #[test]
@@ -41,3 +188,54 @@ fn flipping() {
     assert_eq!(IDENTITY, FLIP_Y * FLIP_Y);
     assert_eq!(IDENTITY, FLIP_X * FLIP_X);
 }
+
+#[test]
+fn dihedral_tables() {
+    assert_eq!(FLIP_Y, D4[6]);
+
+    for i in 0..8 {
+        for j in 0..8 {
+            assert_eq!(D4[i] * D4[j], D4_PRODUCTS[i][j]);
+            assert!(D4.contains(&D4_PRODUCTS[i][j]));
+        }
+    }
+
+    const TWICE: Matrix<i64> = Matrix { a: 2, b: 1, c: 1, d: 1 };
+    assert_eq!(Matrix::new(5, 3, 3, 2), TWICE.const_mul(&TWICE));
+}
+
+#[test]
+fn rotation_objects() {
+    let quarter = Rotation2::new(::std::f64::consts::FRAC_PI_2);
+    let v = quarter * Vector::new(1.0, 0.0);
+
+    assert!(v.distance(Vector::new(0.0, 1.0)) < 1e-12);
+    assert_eq!(Rotation2::new(0.0), quarter * quarter.inverse());
+    assert_eq!(rotation(0.5), Rotation2::new(0.5).matrix());
+    assert!((0.5 - Rotation2::from_matrix(&rotation(0.5)).angle()).abs() < 1e-12);
+}
+
+#[test]
+fn rotating_3d() {
+    use std::f64::consts::FRAC_PI_2;
+
+    let close = |a: Vector3<f64>, b: Vector3<f64>| (a - b).length() < 1e-12;
+    let (x, y, z) = (Vector3::new(1.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0), Vector3::new(0.0, 0.0, 1.0));
+
+    assert!(close(z, rotation_x(FRAC_PI_2) * y));
+    assert!(close(x, rotation_y(FRAC_PI_2) * z));
+    assert!(close(y, rotation_z(FRAC_PI_2) * x));
+
+    for &(axis, basic) in [(x, rotation_x(0.7)), (y, rotation_y(0.7)), (z, rotation_z(0.7))].iter() {
+        let general = rotation_axis_angle(axis * 3.0, 0.7);
+
+        for &v in [x, y, z].iter() {
+            assert!(close(basic * v, general * v));
+        }
+    }
+
+    let r = rotation_axis_angle(Vector3::new(1.0, 1.0, 1.0), 2.0 * ::std::f64::consts::FRAC_PI_3);
+    assert!(close(y, r * x));
+    assert!((1.0 - r.determinant()).abs() < 1e-12);
+    assert_eq!(rotation(0.4).a, rotation_z(0.4).get(0, 0));
+}
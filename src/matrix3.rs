@@ -0,0 +1,483 @@
+use std::fmt;
+use std::fmt::Display;
+use std::ops::{Add, Mul, Neg, Sub};
+
+use linear_transforms::{rotation_x, rotation_y, rotation_z};
+use num::{One, Zero};
+
+/// Represents a 3x3 matrix with entries of type T.
+///
+/// Internally stored as rows: [[m[0][0], m[0][1], m[0][2]], ...]
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(C)]
+pub struct Matrix3<T> where T: Copy {
+    m: [[T; 3]; 3]
+}
+
+/// Represents a 3-vector with entries of type T.
+///
+/// Internally stored as: transpose([x, y, z])
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(C)]
+pub struct Vector3<T> where T: Copy {
+    x: T,
+    y: T,
+    z: T
+}
+
+/// An order of rotations about the coordinate axes, for Euler angles.
+///
+/// XYZ means rotating about the x axis first, then y, then z, each about the
+/// fixed axes, so the matrix is Rz * Ry * Rx.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EulerOrder {
+    XYZ,
+    XZY,
+    YXZ,
+    YZX,
+    ZXY,
+    ZYX
+}
+
+impl EulerOrder {
+    /// The axis indices in the order the rotations are applied.
+    fn axes(&self) -> (usize, usize, usize) {
+        match *self {
+            EulerOrder::XYZ => (0, 1, 2),
+            EulerOrder::XZY => (0, 2, 1),
+            EulerOrder::YXZ => (1, 0, 2),
+            EulerOrder::YZX => (1, 2, 0),
+            EulerOrder::ZXY => (2, 0, 1),
+            EulerOrder::ZYX => (2, 1, 0)
+        }
+    }
+}
+
+
+// Vanilla Methods
+
+impl<T> Matrix3<T> where T: Copy {
+    pub fn new(rows: [[T; 3]; 3]) -> Matrix3<T> {
+        Matrix3 {
+            m: rows
+        }
+    }
+
+    pub fn identity() -> Matrix3<T> where T: Zero + One {
+        let (o, i) = (T::zero(), T::one());
+
+        Matrix3::new([
+            [i, o, o],
+            [o, i, o],
+            [o, o, i]
+        ])
+    }
+
+    pub fn from_columns(x: Vector3<T>, y: Vector3<T>, z: Vector3<T>) -> Matrix3<T> {
+        Matrix3::new([
+            [x.x, y.x, z.x],
+            [x.y, y.y, z.y],
+            [x.z, y.z, z.z]
+        ])
+    }
+
+    /// The skew-symmetric "hat" matrix of v, with cross_matrix(v) * u = v x u.
+    pub fn cross_matrix(v: Vector3<T>) -> Matrix3<T> where T: Zero + Neg<Output=T> {
+        let o = T::zero();
+
+        Matrix3::new([
+            [o,    -v.z, v.y ],
+            [v.z,  o,    -v.x],
+            [-v.y, v.x,  o   ]
+        ])
+    }
+
+    /// The entry in row i and column j, each 0, 1 or 2.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        self.m[i][j]
+    }
+
+    pub fn row(&self, i: usize) -> Vector3<T> {
+        Vector3::new(self.m[i][0], self.m[i][1], self.m[i][2])
+    }
+
+    pub fn column(&self, j: usize) -> Vector3<T> {
+        Vector3::new(self.m[0][j], self.m[1][j], self.m[2][j])
+    }
+
+    pub fn transpose(&self) -> Matrix3<T> {
+        Matrix3::from_columns(self.row(0), self.row(1), self.row(2))
+    }
+
+    pub fn scale(&self, factor: T) -> Matrix3<T> where T: Mul<Output=T> {
+        Matrix3::from_columns(
+            self.column(0).scale(factor),
+            self.column(1).scale(factor),
+            self.column(2).scale(factor)
+        )
+    }
+
+    pub fn trace(&self) -> T where T: Add<Output=T> {
+        self.m[0][0] + self.m[1][1] + self.m[2][2]
+    }
+
+    /// The determinant, by cofactor expansion along the first row.
+    pub fn determinant(&self) -> T where T: Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+        let m = &self.m;
+
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+}
+
+impl<T> Vector3<T> where T: Copy {
+    pub fn new(x: T, y: T, z: T) -> Vector3<T> {
+        Vector3 {
+            x,
+            y,
+            z
+        }
+    }
+
+    pub fn x(&self) -> T {
+        self.x
+    }
+
+    pub fn y(&self) -> T {
+        self.y
+    }
+
+    pub fn z(&self) -> T {
+        self.z
+    }
+
+    pub fn scale(&self, factor: T) -> Vector3<T> where T: Mul<Output=T> {
+        Vector3::new(
+            self.x * factor,
+            self.y * factor,
+            self.z * factor
+        )
+    }
+
+    pub fn dot(&self, other: Vector3<T>) -> T where T: Mul<Output=T> + Add<Output=T> {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    /// The cross product self x other, perpendicular to both.
+    pub fn cross(&self, other: Vector3<T>) -> Vector3<T> where T: Mul<Output=T> + Sub<Output=T> {
+        Vector3::new(
+            self.y * other.z - self.z * other.y,
+            self.z * other.x - self.x * other.z,
+            self.x * other.y - self.y * other.x
+        )
+    }
+
+    /// The scalar triple product self . (b x c), the signed volume of the
+    /// parallelepiped on the three vectors.
+    ///
+    pub fn scalar_triple(&self, b: Vector3<T>, c: Vector3<T>) -> T where T: Mul<Output=T> + Add<Output=T> + Sub<Output=T> {
+        self.dot(b.cross(c))
+    }
+
+    /// The vector triple product self x (b x c).
+    pub fn vector_triple(&self, b: Vector3<T>, c: Vector3<T>) -> Vector3<T> where T: Mul<Output=T> + Sub<Output=T> {
+        self.cross(b.cross(c))
+    }
+}
+
+// Float Methods
+
+impl Matrix3<f64> {
+    /// The rotation by angles.0, angles.1 and angles.2 radians about the axes
+    /// of order, applied in that order.
+    ///
+    pub fn from_euler(order: EulerOrder, angles: (f64, f64, f64)) -> Matrix3<f64> {
+        let (i, j, k) = order.axes();
+        let axis = |index: usize, radians: f64| match index {
+            0 => rotation_x(radians),
+            1 => rotation_y(radians),
+            _ => rotation_z(radians)
+        };
+
+        axis(k, angles.2) * axis(j, angles.1) * axis(i, angles.0)
+    }
+
+    /// The inverse matrix, by the adjugate, or None if this one is singular.
+    pub fn inverse(&self) -> Option<Matrix3<f64>> {
+        let det = self.determinant();
+
+        if det == 0.0 {
+            return None;
+        }
+
+        // the rows of the inverse are the cross products of pairs of columns
+        let (c0, c1, c2) = (self.column(0), self.column(1), self.column(2));
+
+        Some(Matrix3::from_columns(c1.cross(c2), c2.cross(c0), c0.cross(c1)).transpose() * (1.0 / det))
+    }
+
+    /// The inverse transpose, which maps surface normals the way this matrix
+    /// maps tangents, or None if this matrix is singular.
+    ///
+    pub fn normal_matrix(&self) -> Option<Matrix3<f64>> {
+        self.inverse().map(|inverse| inverse.transpose())
+    }
+
+    /// Euler angles for this rotation matrix in the given order, inverting
+    /// from_euler with the middle angle in [-pi/2, pi/2].
+    ///
+    /// At gimbal lock, where the middle angle is +-pi/2 and only the sum or
+    /// difference of the others is determined, the last angle is taken as 0.
+    ///
+    pub fn to_euler(&self, order: EulerOrder) -> (f64, f64, f64) {
+        let (i, j, k) = order.axes();
+        let m = &self.m;
+
+        // even permutations of (x, y, z) follow the right-hand rule
+        let sign = if (j + 3 - i) % 3 == 1 { 1.0 } else { -1.0 };
+        let sin_middle = (-sign * m[k][i]).clamp(-1.0, 1.0);
+        let middle = sin_middle.asin();
+
+        if sin_middle.abs() > 1.0 - 1e-12 {
+            let first = (-sign * m[j][k]).atan2(m[j][j]);
+
+            (first, middle, 0.0)
+        } else {
+            let first = (sign * m[k][j]).atan2(m[k][k]);
+            let last = (sign * m[j][i]).atan2(m[i][i]);
+
+            (first, middle, last)
+        }
+    }
+}
+
+impl Vector3<f64> {
+    pub fn length(&self) -> f64 {
+        self.dot(*self).sqrt()
+    }
+
+    /// The unit vector in the same direction.
+    pub fn normalize(&self) -> Vector3<f64> {
+        *self * (1.0 / self.length())
+    }
+}
+
+// Display Methods
+
+impl<T> fmt::Display for Matrix3<T> where T: Copy + Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let m = &self.m;
+
+        write!(f, "[[{} {} {}], [{} {} {}], [{} {} {}]]",
+               m[0][0], m[0][1], m[0][2],
+               m[1][0], m[1][1], m[1][2],
+               m[2][0], m[2][1], m[2][2])
+    }
+}
+
+impl<T> fmt::Display for Vector3<T> where T: Copy + Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[{} {} {}]", self.x, self.y, self.z)
+    }
+}
+
+// Operator Methods
+
+/// Implementation of Matrix3 + Matrix3.
+impl<T> Add<Matrix3<T>> for Matrix3<T> where T: Copy + Add<Output=T> {
+    type Output = Matrix3<T>;
+
+    fn add(self, rhs: Matrix3<T>) -> Matrix3<T> {
+        Matrix3::from_columns(
+            self.column(0) + rhs.column(0),
+            self.column(1) + rhs.column(1),
+            self.column(2) + rhs.column(2)
+        )
+    }
+}
+
+/// Implementation of Matrix3 - Matrix3.
+impl<T> Sub<Matrix3<T>> for Matrix3<T> where T: Copy + Sub<Output=T> {
+    type Output = Matrix3<T>;
+
+    fn sub(self, rhs: Matrix3<T>) -> Matrix3<T> {
+        Matrix3::from_columns(
+            self.column(0) - rhs.column(0),
+            self.column(1) - rhs.column(1),
+            self.column(2) - rhs.column(2)
+        )
+    }
+}
+
+/// Implementation of Matrix3 * scalar.
+impl<T> Mul<T> for Matrix3<T> where T: Copy + Mul<Output=T> {
+    type Output = Matrix3<T>;
+
+    fn mul(self, rhs: T) -> Matrix3<T> {
+        self.scale(rhs)
+    }
+}
+
+/// Implementation of Matrix3 * Vector3.
+impl<T> Mul<Vector3<T>> for Matrix3<T> where T: Copy + Mul<Output=T> + Add<Output=T> {
+    type Output = Vector3<T>;
+
+    fn mul(self, rhs: Vector3<T>) -> Vector3<T> {
+        Vector3::new(
+            self.row(0).dot(rhs),
+            self.row(1).dot(rhs),
+            self.row(2).dot(rhs)
+        )
+    }
+}
+
+/// Implementation of Matrix3 * Matrix3.
+impl<T> Mul<Matrix3<T>> for Matrix3<T> where T: Copy + Mul<Output=T> + Add<Output=T> {
+    type Output = Matrix3<T>;
+
+    fn mul(self, rhs: Matrix3<T>) -> Matrix3<T> {
+        Matrix3::from_columns(
+            self * rhs.column(0),
+            self * rhs.column(1),
+            self * rhs.column(2)
+        )
+    }
+}
+
+/// Implementation of Vector3 + Vector3.
+impl<T> Add<Vector3<T>> for Vector3<T> where T: Copy + Add<Output=T> {
+    type Output = Vector3<T>;
+
+    fn add(self, rhs: Vector3<T>) -> Vector3<T> {
+        Vector3::new(
+            self.x + rhs.x,
+            self.y + rhs.y,
+            self.z + rhs.z
+        )
+    }
+}
+
+/// Implementation of Vector3 - Vector3.
+impl<T> Sub<Vector3<T>> for Vector3<T> where T: Copy + Sub<Output=T> {
+    type Output = Vector3<T>;
+
+    fn sub(self, rhs: Vector3<T>) -> Vector3<T> {
+        Vector3::new(
+            self.x - rhs.x,
+            self.y - rhs.y,
+            self.z - rhs.z
+        )
+    }
+}
+
+/// Implementation of Vector3 * scalar.
+impl<T> Mul<T> for Vector3<T> where T: Copy + Mul<Output=T> {
+    type Output = Vector3<T>;
+
+    fn mul(self, rhs: T) -> Vector3<T> {
+        self.scale(rhs)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use EulerOrder;
+    use Matrix3;
+    use Vector3;
+
+    #[test]
+    fn construction() {
+        let m = Matrix3::new([[1, 2, 3], [4, 5, 6], [7, 8, 10]]);
+
+        assert_eq!(6, m.get(1, 2));
+        assert_eq!(Vector3::new(4, 5, 6), m.row(1));
+        assert_eq!(Vector3::new(3, 6, 10), m.column(2));
+        assert_eq!(m, Matrix3::from_columns(m.column(0), m.column(1), m.column(2)));
+        assert_eq!(m, m.transpose().transpose());
+        assert_eq!(m.row(0), m.transpose().column(0));
+        assert_eq!("[[1 2 3], [4 5 6], [7 8 10]]", format!("{}", m));
+        assert_eq!("[1 2 3]", format!("{}", Vector3::new(1, 2, 3)));
+    }
+
+    #[test]
+    fn algebra() {
+        let m = Matrix3::new([[1, 2, 3], [4, 5, 6], [7, 8, 10]]);
+        let i: Matrix3<i32> = Matrix3::identity();
+        let v = Vector3::new(1, 0, -1);
+
+        assert_eq!(16, m.trace());
+        assert_eq!(-3, m.determinant());
+        assert_eq!(m, i * m);
+        assert_eq!(m * m.determinant(), m.scale(-3));
+        assert_eq!(Vector3::new(-2, -2, -3), m * v);
+        assert_eq!((m * m) * v, m * (m * v));
+        assert_eq!(m + m, m * 2);
+        assert_eq!(Matrix3::new([[0; 3]; 3]), m - m);
+        assert_eq!(0, v.dot(Vector3::new(1, 5, 1)));
+        assert!((Vector3::new(0.6, 0.0, 0.8) - Vector3::new(3.0, 0.0, 4.0).normalize()).length() < 1e-12);
+    }
+
+    #[test]
+    fn euler_angles() {
+        let orders = [EulerOrder::XYZ, EulerOrder::XZY, EulerOrder::YXZ, EulerOrder::YZX, EulerOrder::ZXY, EulerOrder::ZYX];
+        let distance = |a: Matrix3<f64>, b: Matrix3<f64>| {
+            let d = a - b;
+            (0..3).map(|i| d.row(i).dot(d.row(i))).sum::<f64>().sqrt()
+        };
+
+        for &order in orders.iter() {
+            for &angles in [(0.3, -0.4, 1.2), (-2.0, 1.0, 0.1), (0.5, ::std::f64::consts::FRAC_PI_2, 0.2)].iter() {
+                let m = Matrix3::from_euler(order, angles);
+                let recovered = m.to_euler(order);
+
+                assert!(distance(m, Matrix3::from_euler(order, recovered)) < 1e-9);
+            }
+
+            let (a, b, c) = Matrix3::from_euler(order, (0.3, -0.4, 1.2)).to_euler(order);
+            assert!((a - 0.3).abs() < 1e-12 && (b + 0.4).abs() < 1e-12 && (c - 1.2).abs() < 1e-12);
+        }
+
+        // XYZ applies x first
+        let m = Matrix3::from_euler(EulerOrder::XYZ, (::std::f64::consts::FRAC_PI_2, 0.0, ::std::f64::consts::FRAC_PI_2));
+        assert!((Vector3::new(1.0, 0.0, 0.0) - m * Vector3::new(0.0, 0.0, 1.0)).length() < 1e-12);
+    }
+
+    #[test]
+    fn products() {
+        let (x, y, z) = (Vector3::new(1, 0, 0), Vector3::new(0, 1, 0), Vector3::new(0, 0, 1));
+        let (a, b, c) = (Vector3::new(1, 2, 3), Vector3::new(-1, 0, 4), Vector3::new(2, 5, -2));
+
+        assert_eq!(z, x.cross(y));
+        assert_eq!(x, y.cross(z));
+        assert_eq!(Vector3::new(0, 0, -1), y.cross(x));
+        assert_eq!(0, a.dot(a.cross(b)));
+
+        let m = Matrix3::from_columns(a, b, c);
+        assert_eq!(m.determinant(), a.scalar_triple(b, c));
+        assert_eq!(b.scalar_triple(c, a), a.scalar_triple(b, c));
+
+        // a x (b x c) = b (a . c) - c (a . b)
+        assert_eq!(b * a.dot(c) - c * a.dot(b), a.vector_triple(b, c));
+
+        assert_eq!(a.cross(b), Matrix3::cross_matrix(a) * b);
+        assert_eq!(Matrix3::cross_matrix(a) * -1, Matrix3::cross_matrix(a).transpose());
+    }
+
+    #[test]
+    fn inverses() {
+        let m = Matrix3::new([[2.0, 0.0, 1.0], [1.0, 3.0, 0.0], [0.0, 1.0, 1.0]]);
+        let product = m * m.inverse().unwrap() - Matrix3::identity();
+
+        assert!((0..3).all(|i| product.row(i).length() < 1e-12));
+        assert_eq!(None, Matrix3::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]]).inverse());
+
+        let stretch = Matrix3::new([[3.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]]);
+        let (tangent, normal) = (Vector3::new(1.0, -3.0, 0.0), Vector3::new(3.0, 1.0, 2.0));
+        assert_eq!(0.0, (stretch.normal_matrix().unwrap() * normal).dot(stretch * tangent));
+    }
+}
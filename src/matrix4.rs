@@ -0,0 +1,399 @@
+use std::fmt;
+use std::fmt::Display;
+use std::ops::{Add, Mul};
+
+use Isometry3;
+use Matrix;
+use Plane;
+use Vector3;
+
+/// Represents a 4x4 matrix with entries of type T.
+///
+/// Internally stored as its four columns, so being repr(C) it is laid out in
+/// the column-major order OpenGL, Vulkan and WebGPU expect of a uniform.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(C)]
+pub struct Matrix4<T> where T: Copy {
+    cols: [[T; 4]; 4]
+}
+
+/// The range clip-space depth is mapped to by a projection.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DepthRange {
+    /// [-1, 1], as in OpenGL.
+    NegativeOneToOne,
+
+    /// [0, 1], as in Vulkan, WebGPU, Metal and Direct3D.
+    ZeroToOne
+}
+
+
+// Vanilla Methods
+
+impl<T> Matrix4<T> where T: Copy {
+    pub fn from_blocks(a: Matrix<T>, b: Matrix<T>, c: Matrix<T>, d: Matrix<T>) -> Matrix4<T> {
+        Matrix4::from_rows([
+            [a.a, a.b, b.a, b.b],
+            [a.c, a.d, b.c, b.d],
+            [c.a, c.b, d.a, d.b],
+            [c.c, c.d, d.c, d.d]
+        ])
+    }
+
+    pub fn from_rows(rows: [[T; 4]; 4]) -> Matrix4<T> {
+        let col = |j: usize| [rows[0][j], rows[1][j], rows[2][j], rows[3][j]];
+
+        Matrix4::from_cols([col(0), col(1), col(2), col(3)])
+    }
+
+    pub fn from_cols(cols: [[T; 4]; 4]) -> Matrix4<T> {
+        Matrix4 {
+            cols
+        }
+    }
+
+    /// The entry in row i and column j, each from 0 to 3.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        self.cols[j][i]
+    }
+
+    pub fn to_rows(&self) -> [[T; 4]; 4] {
+        self.transpose().cols
+    }
+
+    pub fn to_cols(&self) -> [[T; 4]; 4] {
+        self.cols
+    }
+
+    /// Returns the 2x2 block at block-row i and block-column j, each 0 or 1.
+    pub fn block(&self, i: usize, j: usize) -> Matrix<T> {
+        if i > 1 || j > 1 {
+            panic!("block index ({}, {}) out of range", i, j);
+        }
+
+        Matrix::new(
+            self.get(2 * i, 2 * j),     self.get(2 * i, 2 * j + 1),
+            self.get(2 * i + 1, 2 * j), self.get(2 * i + 1, 2 * j + 1)
+        )
+    }
+
+    pub fn transpose(&self) -> Matrix4<T> {
+        Matrix4::from_rows(self.cols)
+    }
+}
+
+// Float Methods
+
+impl Matrix4<f64> {
+    /// A right-handed perspective projection, looking down -z, with vertical
+    /// field of view fov radians and aspect ratio width / height.
+    ///
+    /// Depth at the near and far planes maps to the ends of depth. Clip space
+    /// has y up, so Vulkan users flip y in the viewport.
+    ///
+    pub fn perspective(fov: f64, aspect: f64, near: f64, far: f64, depth: DepthRange) -> Matrix4<f64> {
+        let f = 1.0 / (fov / 2.0).tan();
+        let (zz, zw) = match depth {
+            DepthRange::NegativeOneToOne => ((far + near) / (near - far), 2.0 * far * near / (near - far)),
+            DepthRange::ZeroToOne        => (far / (near - far), far * near / (near - far))
+        };
+
+        Matrix4::from_rows([
+            [f / aspect, 0.0, 0.0,  0.0],
+            [0.0,        f,   0.0,  0.0],
+            [0.0,        0.0, zz,   zw ],
+            [0.0,        0.0, -1.0, 0.0]
+        ])
+    }
+
+    /// A right-handed orthographic projection of the box [l, r] x [b, t] x
+    /// [-n, -f], with the same conventions as perspective.
+    ///
+    pub fn orthographic(l: f64, r: f64, b: f64, t: f64, n: f64, f: f64, depth: DepthRange) -> Matrix4<f64> {
+        let (zz, zw) = match depth {
+            DepthRange::NegativeOneToOne => (-2.0 / (f - n), -(f + n) / (f - n)),
+            DepthRange::ZeroToOne        => (-1.0 / (f - n), -n / (f - n))
+        };
+
+        Matrix4::from_rows([
+            [2.0 / (r - l), 0.0,           0.0, -(r + l) / (r - l)],
+            [0.0,           2.0 / (t - b), 0.0, -(t + b) / (t - b)],
+            [0.0,           0.0,           zz,  zw                ],
+            [0.0,           0.0,           0.0, 1.0               ]
+        ])
+    }
+
+    /// The right-handed view matrix of a camera at eye looking at target; see
+    /// Isometry3::look_at_rh.
+    ///
+    pub fn look_at_rh(eye: Vector3<f64>, target: Vector3<f64>, up: Vector3<f64>) -> Matrix4<f64> {
+        Isometry3::look_at_rh(eye, target, up).to_matrix4()
+    }
+
+    /// The left-handed view matrix of a camera at eye looking at target; see
+    /// Isometry3::look_at_lh.
+    ///
+    pub fn look_at_lh(eye: Vector3<f64>, target: Vector3<f64>, up: Vector3<f64>) -> Matrix4<f64> {
+        Isometry3::look_at_lh(eye, target, up).to_matrix4()
+    }
+
+    /// The inverse matrix, by Gauss-Jordan elimination with partial pivoting,
+    /// or None if this one is singular.
+    ///
+    pub fn inverse(&self) -> Option<Matrix4<f64>> {
+        let mut left = self.to_rows();
+        let mut right = [
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0],
+            [0.0, 0.0, 0.0, 1.0]
+        ];
+
+        for col in 0..4 {
+            let pivot = (col..4).max_by(|&i, &j| left[i][col].abs().total_cmp(&left[j][col].abs())).unwrap();
+
+            if left[pivot][col] == 0.0 {
+                return None;
+            }
+            left.swap(col, pivot);
+            right.swap(col, pivot);
+
+            let scale = 1.0 / left[col][col];
+            for x in left[col].iter_mut().chain(right[col].iter_mut()) {
+                *x *= scale;
+            }
+
+            for i in (0..4).filter(|&i| i != col) {
+                let factor = left[i][col];
+
+                for j in 0..4 {
+                    left[i][j] -= factor * left[col][j];
+                    right[i][j] -= factor * right[col][j];
+                }
+            }
+        }
+
+        Some(Matrix4::from_rows(right))
+    }
+
+    /// The six clip planes (left, right, bottom, top, near, far) of the
+    /// frustum this projection matrix maps to the clip volume, with unit
+    /// normals pointing inwards.
+    ///
+    /// Uses the Gribb-Hartmann extraction from the rows of the matrix; for a
+    /// combined projection * view matrix the planes are in world space.
+    ///
+    pub fn frustum_planes(&self, depth: DepthRange) -> [Plane; 6] {
+        let row = |i: usize| (Vector3::new(self.get(i, 0), self.get(i, 1), self.get(i, 2)), self.get(i, 3));
+        let (r0, r1, r2, w) = (row(0), row(1), row(2), row(3));
+        let plane = |(n, d): (Vector3<f64>, f64), sign: f64, (m, e): (Vector3<f64>, f64)| {
+            Plane::new(n + m * sign, d + e * sign).normalize()
+        };
+
+        let near = match depth {
+            DepthRange::NegativeOneToOne => plane(w, 1.0, r2),
+            DepthRange::ZeroToOne        => Plane::new(r2.0, r2.1).normalize()
+        };
+
+        [
+            plane(w, 1.0, r0),
+            plane(w, -1.0, r0),
+            plane(w, 1.0, r1),
+            plane(w, -1.0, r1),
+            near,
+            plane(w, -1.0, r2)
+        ]
+    }
+
+    /// The point p mapped as [p 1]^t and divided through by the resulting w.
+    pub fn project_point(&self, p: Vector3<f64>) -> Vector3<f64> {
+        let row = |i: usize| self.get(i, 0) * p.x() + self.get(i, 1) * p.y() + self.get(i, 2) * p.z() + self.get(i, 3);
+        let w = row(3);
+
+        Vector3::new(row(0) / w, row(1) / w, row(2) / w)
+    }
+}
+
+/// Conversion from a 2x2 matrix of 2x2 blocks, as produced by Matrix::kronecker.
+impl<T> From<Matrix<Matrix<T>>> for Matrix4<T> where T: Copy {
+    fn from(blocks: Matrix<Matrix<T>>) -> Matrix4<T> {
+        Matrix4::from_blocks(blocks.a, blocks.b, blocks.c, blocks.d)
+    }
+}
+
+// Display Methods
+
+impl<T> fmt::Display for Matrix4<T> where T: Copy + Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let r = self.to_rows();
+
+        write!(f, "[[{} {} {} {}], [{} {} {} {}], [{} {} {} {}], [{} {} {} {}]]",
+               r[0][0], r[0][1], r[0][2], r[0][3],
+               r[1][0], r[1][1], r[1][2], r[1][3],
+               r[2][0], r[2][1], r[2][2], r[2][3],
+               r[3][0], r[3][1], r[3][2], r[3][3])
+    }
+}
+
+// Operator Methods
+
+/// Implementation of Matrix4 + Matrix4.
+impl<T> Add<Matrix4<T>> for Matrix4<T> where T: Copy + Add<Output=T> {
+    type Output = Matrix4<T>;
+
+    fn add(self, rhs: Matrix4<T>) -> Matrix4<T> {
+        let (l, r) = (self.cols, rhs.cols);
+        let col = |j: usize| [l[j][0] + r[j][0], l[j][1] + r[j][1], l[j][2] + r[j][2], l[j][3] + r[j][3]];
+
+        Matrix4::from_cols([col(0), col(1), col(2), col(3)])
+    }
+}
+
+/// Implementation of Matrix4 * Matrix4, by block multiplication.
+impl<T> Mul<Matrix4<T>> for Matrix4<T> where T: Copy + Mul<Output=T> + Add<Output=T> {
+    type Output = Matrix4<T>;
+
+    fn mul(self, rhs: Matrix4<T>) -> Matrix4<T> {
+        let (a, b, c, d) = (self.block(0, 0), self.block(0, 1), self.block(1, 0), self.block(1, 1));
+        let (e, f, g, h) = (rhs.block(0, 0), rhs.block(0, 1), rhs.block(1, 0), rhs.block(1, 1));
+
+        Matrix4::from_blocks(
+            a * e + b * g,
+            a * f + b * h,
+            c * e + d * g,
+            c * f + d * h
+        )
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use DepthRange;
+    use Isometry3;
+    use Matrix;
+    use Matrix4;
+    use Vector3;
+
+    #[test]
+    fn blocks() {
+        let a = Matrix::new(1, 2, 3, 4);
+        let b = Matrix::new(5, 6, 7, 8);
+        let c = Matrix::new(9, 10, 11, 12);
+        let d = Matrix::new(13, 14, 15, 16);
+        let m = Matrix4::from_blocks(a, b, c, d);
+
+        assert_eq!(a, m.block(0, 0));
+        assert_eq!(b, m.block(0, 1));
+        assert_eq!(c, m.block(1, 0));
+        assert_eq!(d, m.block(1, 1));
+
+        assert_eq!(
+            Matrix4::from_blocks(a.transpose(), c.transpose(), b.transpose(), d.transpose()),
+            m.transpose()
+        );
+        assert_eq!(
+            "[[1 2 5 6], [3 4 7 8], [9 10 13 14], [11 12 15 16]]",
+            format!("{}", m)
+        );
+    }
+
+    #[test]
+    fn block_algebra() {
+        let i = Matrix::new(1, 0, 0, 1);
+        let o = Matrix::new(0, 0, 0, 0);
+        let m = Matrix::new(1, 2, 3, 4);
+        let identity = Matrix4::from_blocks(i, o, o, i);
+        let n = Matrix4::from_blocks(m, i, o, m);
+
+        assert_eq!(n, identity * n);
+        assert_eq!(Matrix4::from_blocks(m * m, m + m, o, m * m), n * n);
+        assert_eq!(Matrix4::from_blocks(m + i, i, o, m + i), n + identity);
+
+        // kronecker products convert directly
+        assert_eq!(n, Matrix4::from(Matrix::new(m, i, o, m)));
+        assert_eq!(Matrix4::from_blocks(m, o, o, m), Matrix4::from(i.kronecker(&m)));
+    }
+
+    #[test]
+    fn rows() {
+        let m = Matrix4::from_rows([[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12], [13, 14, 15, 16]]);
+
+        assert_eq!(Matrix::new(3, 4, 7, 8), m.block(0, 1));
+        assert_eq!(12, m.get(2, 3));
+        assert_eq!(14, m.get(3, 1));
+        assert_eq!(m, Matrix4::from_rows(m.to_rows()));
+        assert_eq!([[1, 5, 9, 13], [2, 6, 10, 14], [3, 7, 11, 15], [4, 8, 12, 16]], m.to_cols());
+        assert_eq!(m, Matrix4::from_cols(m.to_cols()));
+        assert_eq!("[[1 2 3 4], [5 6 7 8], [9 10 11 12], [13 14 15 16]]", format!("{}", m));
+    }
+
+    #[test]
+    fn projections() {
+        let close = |a: Vector3<f64>, b: Vector3<f64>| (a - b).length() < 1e-12;
+
+        let gl = Matrix4::perspective(FRAC_PI_2, 2.0, 1.0, 10.0, DepthRange::NegativeOneToOne);
+        let vk = Matrix4::perspective(FRAC_PI_2, 2.0, 1.0, 10.0, DepthRange::ZeroToOne);
+
+        assert!(close(Vector3::new(0.0, 0.0, -1.0), gl.project_point(Vector3::new(0.0, 0.0, -1.0))));
+        assert!(close(Vector3::new(0.0, 0.0, 1.0),  gl.project_point(Vector3::new(0.0, 0.0, -10.0))));
+        assert!(close(Vector3::new(0.0, 0.0, 0.0),  vk.project_point(Vector3::new(0.0, 0.0, -1.0))));
+        assert!(close(Vector3::new(0.0, 0.0, 1.0),  vk.project_point(Vector3::new(0.0, 0.0, -10.0))));
+
+        // a 90 degree field of view puts the top edge at y = -z
+        assert!((1.0 - gl.project_point(Vector3::new(0.0, 5.0, -5.0)).y()).abs() < 1e-12);
+        assert!((1.0 - gl.project_point(Vector3::new(10.0, 0.0, -5.0)).x()).abs() < 1e-12);
+
+        let ortho = Matrix4::orthographic(-2.0, 2.0, -1.0, 1.0, 1.0, 3.0, DepthRange::NegativeOneToOne);
+        assert!(close(Vector3::new(-1.0, 1.0, -1.0), ortho.project_point(Vector3::new(-2.0, 1.0, -1.0))));
+        assert!(close(Vector3::new(0.5, 0.0, 1.0),   ortho.project_point(Vector3::new(1.0, 0.0, -3.0))));
+
+        let ortho = Matrix4::orthographic(0.0, 4.0, 0.0, 2.0, 1.0, 3.0, DepthRange::ZeroToOne);
+        assert!(close(Vector3::new(-1.0, -1.0, 0.0), ortho.project_point(Vector3::new(0.0, 0.0, -1.0))));
+        assert!(close(Vector3::new(1.0, 1.0, 1.0),   ortho.project_point(Vector3::new(4.0, 2.0, -3.0))));
+    }
+
+    #[test]
+    fn inverse() {
+        let m = Matrix4::perspective(1.0, 1.5, 0.5, 20.0, DepthRange::ZeroToOne)
+            * Matrix4::look_at_rh(Vector3::new(1.0, 2.0, 3.0), Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+        let p = Vector3::new(0.3, -0.2, 0.5);
+
+        assert!((p - m.inverse().unwrap().project_point(m.project_point(p))).length() < 1e-12);
+        assert_eq!(None, (Matrix4::from_rows([[1.0, 2.0, 3.0, 4.0], [2.0, 4.0, 6.0, 8.0], [0.0, 0.0, 1.0, 0.0], [0.0, 0.0, 0.0, 1.0]])).inverse());
+    }
+
+    #[test]
+    fn frustum() {
+        for &depth in [DepthRange::NegativeOneToOne, DepthRange::ZeroToOne].iter() {
+            let view = Isometry3::look_at_rh(Vector3::new(0.0, 0.0, 5.0), Vector3::new(0.0, 0.0, 0.0), Vector3::new(0.0, 1.0, 0.0));
+            let m = Matrix4::perspective(FRAC_PI_2, 1.0, 1.0, 10.0, depth) * view.to_matrix4();
+            let planes = m.frustum_planes(depth);
+            let inside = |p: Vector3<f64>| planes.iter().all(|plane| plane.signed_distance(p) >= 0.0);
+
+            assert!(inside(Vector3::new(0.0, 0.0, 0.0)));
+            assert!(inside(Vector3::new(1.9, -1.9, 2.9)));
+            assert!(!inside(Vector3::new(0.0, 0.0, 4.5)));
+            assert!(!inside(Vector3::new(0.0, 0.0, -5.5)));
+            assert!(!inside(Vector3::new(5.0, 0.0, 0.0)));
+
+            // the near plane is 4 units from the origin, the far plane 5
+            assert!((4.0 - planes[4].signed_distance(Vector3::new(0.0, 0.0, 0.0))).abs() < 1e-12);
+            assert!((5.0 - planes[5].signed_distance(Vector3::new(0.0, 0.0, 0.0))).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn block_out_of_range() {
+        Matrix4::from_blocks(
+            Matrix::new(1, 0, 0, 1),
+            Matrix::new(0, 0, 0, 0),
+            Matrix::new(0, 0, 0, 0),
+            Matrix::new(1, 0, 0, 1)
+        ).block(2, 0);
+    }
+}
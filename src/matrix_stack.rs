@@ -0,0 +1,74 @@
+use std::cell::RefCell;
+
+use Affine2;
+use TransformStack;
+
+thread_local! {
+    /// A transform stack in the style of fixed-function OpenGL's glPushMatrix
+    /// and glPopMatrix, for teaching and porting old code. Each thread has its
+    /// own, starting at the identity; new code should pass a TransformStack
+    /// around instead.
+    ///
+    static STACK: RefCell<TransformStack> = RefCell::new(TransformStack::new());
+}
+
+/// Saves the current transform, to be restored by the next pop_matrix.
+pub fn push_matrix() {
+    STACK.with(|stack| stack.borrow_mut().push());
+}
+
+/// Restores the most recently pushed transform, returning the one discarded,
+/// or None if nothing has been pushed.
+///
+pub fn pop_matrix() -> Option<Affine2<f64>> {
+    STACK.with(|stack| stack.borrow_mut().pop())
+}
+
+/// Composes t onto the current transform, so that it acts on coordinates first.
+pub fn mult_matrix(t: Affine2<f64>) {
+    STACK.with(|stack| stack.borrow_mut().apply(t));
+}
+
+/// Replaces the whole stack with a single identity transform.
+pub fn load_identity() {
+    STACK.with(|stack| *stack.borrow_mut() = TransformStack::new());
+}
+
+pub fn current() -> Affine2<f64> {
+    STACK.with(|stack| stack.borrow().current())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use matrix_stack::{current, load_identity, mult_matrix, pop_matrix, push_matrix};
+    use Affine2;
+    use Matrix;
+    use Point2;
+    use Vector;
+
+    #[test]
+    fn stack() {
+        let shift = Affine2::from_translation(Vector::new(1.0, 0.0));
+        let double = Affine2::from_linear(Matrix::new(2.0, 0.0, 0.0, 2.0));
+
+        load_identity();
+        mult_matrix(shift);
+        push_matrix();
+        mult_matrix(double);
+        assert_eq!(Point2::new(3.0, 2.0), current() * Point2::new(1.0, 1.0));
+
+        assert_eq!(Some(shift * double), pop_matrix());
+        assert_eq!(shift, current());
+        assert_eq!(None, pop_matrix());
+
+        // other threads start from the identity
+        let elsewhere = thread::spawn(current).join().unwrap();
+        assert_eq!(Affine2::identity(), elsewhere);
+
+        load_identity();
+        assert_eq!(Affine2::identity(), current());
+    }
+}
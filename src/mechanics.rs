@@ -0,0 +1,170 @@
+use linear_transforms::rotation;
+use Matrix;
+use Rotation2;
+use Vector;
+
+/// The principal values and axes of a symmetric 2D tensor.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Principal {
+    /// The principal values, larger first.
+    pub values: (f64, f64),
+
+    /// Unit directions of the principal axes, in the order of values.
+    pub directions: (Vector<f64>, Vector<f64>),
+
+    /// The angle in radians from the x axis to the first principal direction.
+    pub angle: f64,
+
+    /// The largest shear on any plane, half the difference of the values.
+    pub max_shear: f64
+}
+
+/// The right polar decomposition F = R U of a deformation gradient, with the
+/// strains it implies.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PolarDecomposition {
+    pub rotation: Rotation2,
+
+    /// The symmetric positive definite right stretch U.
+    pub stretch: Matrix<f64>,
+
+    /// The Green-Lagrange strain (F^t F - I) / 2.
+    pub green_lagrange: Matrix<f64>,
+
+    /// The Biot strain U - I.
+    pub biot: Matrix<f64>
+}
+
+/// The symmetric tensor sigma turned by theta radians, R sigma R^t, which is
+/// sigma seen in axes turned by -theta.
+///
+pub fn rotate_tensor(sigma: Matrix<f64>, theta: f64) -> Matrix<f64> {
+    let r = rotation(theta);
+
+    r * sigma * r.transpose()
+}
+
+/// Mohr's circle of the symmetric tensor sigma, as (center, radius).
+///
+/// Every rotated tensor has (normal, shear) = (sigma_xx, sigma_xy) on this
+/// circle; it meets the normal axis at the principal values.
+///
+pub fn mohr_circle(sigma: Matrix<f64>) -> (f64, f64) {
+    let center = (sigma.a + sigma.d) / 2.0;
+    let radius = ((sigma.a - sigma.d) / 2.0).hypot(sigma.b);
+
+    (center, radius)
+}
+
+/// The principal values and directions of the symmetric tensor sigma.
+pub fn principal(sigma: Matrix<f64>) -> Principal {
+    let (angle, (s1, s2), axes) = sigma.symmetric_eigen();
+
+    Principal {
+        values: (s1, s2),
+        directions: (axes.left(), axes.right()),
+        angle,
+        max_shear: (s1 - s2) / 2.0
+    }
+}
+
+/// The largest shear stress on any plane, the radius of Mohr's circle.
+pub fn max_shear(sigma: Matrix<f64>) -> f64 {
+    mohr_circle(sigma).1
+}
+
+/// The right polar decomposition of the deformation gradient f, or None
+/// unless det f > 0, as no physical deformation inverts or flattens material.
+///
+/// Built from the SVD f = W S V^t as R = W V^t and U = V S V^t.
+///
+pub fn polar_decompose_f(f: Matrix<f64>) -> Option<PolarDecomposition> {
+    if f.determinant() <= 0.0 {
+        return None;
+    }
+
+    let identity: Matrix<f64> = Matrix::identity();
+    let (w, (s1, s2), v) = f.svd();
+    let stretch = v * Matrix::new(s1, 0.0, 0.0, s2) * v.transpose();
+
+    Some(PolarDecomposition {
+        rotation: Rotation2::from_matrix(&(w * v.transpose())),
+        stretch,
+        green_lagrange: (f.transpose() * f - identity) * 0.5,
+        biot: stretch - identity
+    })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_4;
+
+    use mechanics::{max_shear, mohr_circle, polar_decompose_f, principal, rotate_tensor};
+    use Matrix;
+    use Rotation2;
+
+    #[test]
+    fn tensors() {
+        let sigma = Matrix::new(50.0, 30.0, 30.0, -10.0);
+        let (center, radius) = mohr_circle(sigma);
+
+        assert_eq!(20.0, center);
+        assert!((30.0 * 2.0f64.sqrt() - radius).abs() < 1e-12);
+
+        for &theta in [0.0, 0.3, 1.0, 2.5].iter() {
+            let rotated = rotate_tensor(sigma, theta);
+
+            assert!((rotated.a + rotated.d - sigma.trace()).abs() < 1e-12);
+            assert!((rotated.b - rotated.c).abs() < 1e-12);
+            assert!(((rotated.a - center).hypot(rotated.b) - radius).abs() < 1e-12);
+        }
+
+        // pure shear turns into equal and opposite normal stresses at 45 degrees
+        let shear = rotate_tensor(Matrix::new(0.0, 5.0, 5.0, 0.0), -FRAC_PI_4);
+        assert!((Matrix::new(5.0, 0.0, 0.0, -5.0) - shear).frobenius_norm() < 1e-12);
+    }
+
+    #[test]
+    fn principal_stresses() {
+        let sigma = Matrix::new(50.0, 30.0, 30.0, -10.0);
+        let p = principal(sigma);
+        let (center, radius) = mohr_circle(sigma);
+
+        assert!((center + radius - p.values.0).abs() < 1e-12);
+        assert!((center - radius - p.values.1).abs() < 1e-12);
+        assert!((max_shear(sigma) - p.max_shear).abs() < 1e-12);
+
+        let (d1, d2) = p.directions;
+        assert!((sigma * d1 - d1 * p.values.0).length() < 1e-12);
+        assert!((sigma * d2 - d2 * p.values.1).length() < 1e-12);
+
+        // rotating onto the principal axes removes the shear
+        let aligned = rotate_tensor(sigma, -p.angle);
+        assert!(aligned.b.abs() < 1e-12);
+        assert!((p.values.0 - aligned.a).abs() < 1e-12);
+    }
+
+    #[test]
+    fn deformation() {
+        let stretch = Matrix::new(1.2, 0.1, 0.1, 0.9);
+        let f = Rotation2::new(0.4).matrix() * stretch;
+        let polar = polar_decompose_f(f).unwrap();
+
+        assert!((0.4 - polar.rotation.angle()).abs() < 1e-12);
+        assert!((stretch - polar.stretch).frobenius_norm() < 1e-12);
+        assert!((f - polar.rotation.matrix() * polar.stretch).frobenius_norm() < 1e-12);
+        assert!((stretch - Matrix::identity() - polar.biot).frobenius_norm() < 1e-12);
+
+        // Green-Lagrange strain ignores the rotation
+        let expected = (stretch * stretch - Matrix::identity()) * 0.5;
+        assert!((expected - polar.green_lagrange).frobenius_norm() < 1e-12);
+
+        let rigid = polar_decompose_f(Rotation2::new(-1.0).matrix()).unwrap();
+        assert!(rigid.green_lagrange.frobenius_norm() < 1e-12);
+
+        assert_eq!(None, polar_decompose_f(Matrix::new(-1.0, 0.0, 0.0, 1.0)));
+        assert_eq!(None, polar_decompose_f(Matrix::new(1.0, 2.0, 2.0, 4.0)));
+    }
+}
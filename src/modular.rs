@@ -0,0 +1,215 @@
+use std::fmt;
+use std::ops::{Add, Mul};
+
+use Matrix;
+
+/// Returns (g, x, y) with g = gcd(a, b) >= 0 and a * x + b * y = g.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut r0, mut r1) = (a, b);
+    let (mut x0, mut x1) = (1, 0);
+    let (mut y0, mut y1) = (0, 1);
+
+    while r1 != 0 {
+        let q = r0 / r1;
+
+        let r = r0 - q * r1;
+        r0 = r1;
+        r1 = r;
+
+        let x = x0 - q * x1;
+        x0 = x1;
+        x1 = x;
+
+        let y = y0 - q * y1;
+        y0 = y1;
+        y1 = y;
+    }
+
+    if r0 < 0 {
+        (-r0, -x0, -y0)
+    } else {
+        (r0, x0, y0)
+    }
+}
+
+/// The x in [0, modulus) with a * x = 1 mod modulus, if a is a unit and
+/// modulus is positive.
+///
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    if modulus <= 0 {
+        return None;
+    }
+
+    let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+
+    if g == 1 {
+        Some(x.rem_euclid(modulus))
+    } else {
+        None
+    }
+}
+
+/// Represents a 2x2 matrix over the integers modulo n.
+///
+/// Entries are kept reduced into [0, n).
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ZnMatrix {
+    matrix: Matrix<i64>,
+    modulus: i64
+}
+
+
+impl ZnMatrix {
+    /// Reduces each entry of matrix modulo modulus, or None unless modulus is
+    /// positive.
+    ///
+    pub fn new(matrix: Matrix<i64>, modulus: i64) -> Option<ZnMatrix> {
+        if modulus <= 0 {
+            return None;
+        }
+
+        Some(ZnMatrix::reduce(matrix, modulus))
+    }
+
+    pub fn matrix(&self) -> Matrix<i64> {
+        self.matrix
+    }
+
+    pub fn modulus(&self) -> i64 {
+        self.modulus
+    }
+
+
+    /// The determinant, reduced into [0, n).
+    pub fn determinant(&self) -> i64 {
+        let m = &self.matrix;
+
+        (self.mul_mod(m.a, m.d) - self.mul_mod(m.b, m.c)).rem_euclid(self.modulus)
+    }
+
+    /// The inverse modulo n, or None unless the determinant is a unit mod n.
+    pub fn inverse(&self) -> Option<ZnMatrix> {
+        let m = &self.matrix;
+
+        mod_inverse(self.determinant(), self.modulus).map(|det_inverse| {
+            ZnMatrix::reduce(
+                Matrix::new(
+                    self.mul_mod(det_inverse, m.d),
+                    self.mul_mod(det_inverse, -m.b),
+                    self.mul_mod(det_inverse, -m.c),
+                    self.mul_mod(det_inverse, m.a)
+                ),
+                self.modulus
+            )
+        })
+    }
+
+    /// matrix with each entry reduced modulo modulus, which is positive.
+    fn reduce(matrix: Matrix<i64>, modulus: i64) -> ZnMatrix {
+        ZnMatrix {
+            matrix: Matrix::new(
+                matrix.a.rem_euclid(modulus),
+                matrix.b.rem_euclid(modulus),
+                matrix.c.rem_euclid(modulus),
+                matrix.d.rem_euclid(modulus)
+            ),
+            modulus
+        }
+    }
+
+    /// x * y mod n, without overflowing for any i64 modulus.
+    fn mul_mod(&self, x: i64, y: i64) -> i64 {
+        ((x as i128 * y as i128).rem_euclid(self.modulus as i128)) as i64
+    }
+}
+
+// Display Methods
+
+impl fmt::Display for ZnMatrix {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} mod {}", self.matrix, self.modulus)
+    }
+}
+
+// Operator Methods
+
+/// Implementation of ZnMatrix + ZnMatrix, for matrices with the same modulus.
+impl Add<ZnMatrix> for ZnMatrix {
+    type Output = ZnMatrix;
+
+    fn add(self, rhs: ZnMatrix) -> ZnMatrix {
+        assert_eq!(self.modulus, rhs.modulus, "moduli must match");
+
+        // the sum of two reduced entries can still exceed i64::MAX
+        let (l, r) = (self.matrix, rhs.matrix);
+        let sum = |x: i64, y: i64| ((x as i128 + y as i128) % self.modulus as i128) as i64;
+
+        ZnMatrix::reduce(
+            Matrix::new(sum(l.a, r.a), sum(l.b, r.b), sum(l.c, r.c), sum(l.d, r.d)),
+            self.modulus
+        )
+    }
+}
+
+/// Implementation of ZnMatrix * ZnMatrix, for matrices with the same modulus.
+impl Mul<ZnMatrix> for ZnMatrix {
+    type Output = ZnMatrix;
+
+    fn mul(self, rhs: ZnMatrix) -> ZnMatrix {
+        assert_eq!(self.modulus, rhs.modulus, "moduli must match");
+
+        let (l, r) = (self.matrix, rhs.matrix);
+        let dot = |w: i64, x: i64, y: i64, z: i64| {
+            ((w as i128 * x as i128 + y as i128 * z as i128) % self.modulus as i128) as i64
+        };
+
+        ZnMatrix::reduce(
+            Matrix::new(
+                dot(l.a, r.a, l.b, r.c),
+                dot(l.a, r.b, l.b, r.d),
+                dot(l.c, r.a, l.d, r.c),
+                dot(l.c, r.b, l.d, r.d)
+            ),
+            self.modulus
+        )
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use modular::{extended_gcd, mod_inverse, ZnMatrix};
+    use Matrix;
+
+    #[test]
+    fn gcds() {
+        assert_eq!((6, -1, 1), extended_gcd(30, 36));
+        assert_eq!((2, -9, 47), extended_gcd(240, 46));
+        assert_eq!(2, extended_gcd(-4, 6).0);
+        assert_eq!(Some(9), mod_inverse(3, 26));
+        assert_eq!(Some(17), mod_inverse(-3, 26));
+        assert_eq!(None, mod_inverse(13, 26));
+        assert_eq!(None, mod_inverse(1, 0));
+        assert_eq!(None, mod_inverse(1, -5));
+    }
+
+    #[test]
+    fn arithmetic() {
+        let m = ZnMatrix::new(Matrix::new(3, 3, 2, 5), 26).unwrap();
+        let identity = ZnMatrix::new(Matrix::identity(), 26).unwrap();
+
+        assert_eq!(Matrix::new(3, 3, 2, 5), ZnMatrix::new(Matrix::new(29, -23, 2, 5), 26).unwrap().matrix());
+        assert_eq!(9, m.determinant());
+        assert_eq!(Matrix::new(15, 17, 20, 9), m.inverse().unwrap().matrix());
+        assert_eq!(identity, m * m.inverse().unwrap());
+        assert_eq!(identity, m.inverse().unwrap() * m);
+        assert_eq!(Matrix::new(6, 6, 4, 10), (m + m).matrix());
+
+        assert_eq!(None, ZnMatrix::new(Matrix::new(2, 0, 0, 1), 26).unwrap().inverse());
+        assert_eq!("[[3 3], [2 5]] mod 26", format!("{}", m));
+
+        assert_eq!(None, ZnMatrix::new(Matrix::identity(), 0));
+        assert_eq!(None, ZnMatrix::new(Matrix::identity(), -26));
+    }
+}
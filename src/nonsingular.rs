@@ -0,0 +1,119 @@
+use std::ops::Mul;
+
+use Matrix;
+use Vector;
+
+/// A matrix known to be invertible, so inverting it and solving with it
+/// cannot fail.
+///
+/// The only way in is the checked constructor, which also rules out
+/// matrices whose inverse overflows, so the inverse is NonSingular too.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NonSingular<T> where T: Copy {
+    matrix: Matrix<T>
+}
+
+
+impl<T> NonSingular<T> where T: Copy {
+    pub fn matrix(&self) -> Matrix<T> {
+        self.matrix
+    }
+}
+
+impl NonSingular<f64> {
+    /// m as a NonSingular, or None if the determinant of it or its inverse
+    /// is zero, or it, its reciprocal or an entry of the inverse is not
+    /// finite.
+    ///
+    pub fn new(m: Matrix<f64>) -> Option<NonSingular<f64>> {
+        let invertible = |m: &Matrix<f64>| {
+            let det = m.determinant();
+
+            det != 0.0 && det.is_finite() && (1.0 / det).is_finite()
+        };
+
+        if !invertible(&m) {
+            return None;
+        }
+
+        let inverse = m.inverse()?;
+        let finite = [inverse.a, inverse.b, inverse.c, inverse.d].iter().all(|x| x.is_finite());
+
+        if !finite || !invertible(&inverse) {
+            return None;
+        }
+
+        Some(NonSingular {
+            matrix: m
+        })
+    }
+
+    pub fn determinant(&self) -> f64 {
+        self.matrix.determinant()
+    }
+
+    pub fn inverse(&self) -> NonSingular<f64> {
+        NonSingular {
+            matrix: self.matrix.inverse().unwrap()
+        }
+    }
+
+    /// The x solving self * x = b.
+    pub fn solve(&self, b: Vector<f64>) -> Vector<f64> {
+        self.matrix.solve(b).unwrap()
+    }
+}
+
+// Operator Methods
+
+/// Implementation of NonSingular * NonSingular, applying rhs first.
+///
+/// The product is checked as in new, so this is None when its determinant,
+/// the product of theirs, underflows or overflows where theirs did not.
+///
+impl Mul<NonSingular<f64>> for NonSingular<f64> {
+    type Output = Option<NonSingular<f64>>;
+
+    fn mul(self, rhs: NonSingular<f64>) -> Option<NonSingular<f64>> {
+        NonSingular::new(self.matrix * rhs.matrix)
+    }
+}
+
+/// Implementation of NonSingular * Vector.
+impl Mul<Vector<f64>> for NonSingular<f64> {
+    type Output = Vector<f64>;
+
+    fn mul(self, rhs: Vector<f64>) -> Vector<f64> {
+        self.matrix * rhs
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use Matrix;
+    use NonSingular;
+    use Vector;
+
+    #[test]
+    fn nonsingular() {
+        let m = NonSingular::new(Matrix::new(2.0, 1.0, 1.0, 1.0)).unwrap();
+        let b = Vector::new(3.0, 2.0);
+
+        assert_eq!(Vector::new(1.0, 1.0), m.solve(b));
+        assert_eq!(b, m * m.solve(b));
+        assert_eq!(Matrix::new(1.0, -1.0, -1.0, 2.0), m.inverse().matrix());
+        assert_eq!(1.0, m.inverse().determinant());
+
+        assert_eq!(None, NonSingular::new(Matrix::new(1.0, 2.0, 2.0, 4.0)));
+        assert_eq!(None, NonSingular::new(Matrix::new(1e-155, 0.0, 0.0, 1e-155)));
+        assert_eq!(None, NonSingular::new(Matrix::new(f64::NAN, 0.0, 0.0, 1.0)));
+        // the determinant inverts, but the inverse's first entry does not
+        assert_eq!(None, NonSingular::new(Matrix::new(1e-310, 0.0, 0.0, 1e10)));
+
+        assert_eq!(NonSingular::new(m.matrix() * m.matrix()), m * m);
+        let tiny = NonSingular::new(Matrix::new(1e-100, 0.0, 0.0, 1e-100)).unwrap();
+        assert_eq!(None, tiny * tiny);
+    }
+}
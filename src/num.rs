@@ -0,0 +1,107 @@
+use std::ops::{Add, Mul, Sub};
+
+/// Types with an additive identity.
+pub trait Zero {
+    fn zero() -> Self;
+}
+
+/// Types with a multiplicative identity.
+pub trait One {
+    fn one() -> Self;
+}
+
+/// Types forming a semiring: Add and Mul are its addition and multiplication,
+/// with identities Zero and One.
+///
+/// Matrix * Matrix and Matrix::pow use nothing more, so they work over any
+/// semiring, such as tropical::MinPlus, whose matrix powers give shortest
+/// paths.
+///
+pub trait Semiring: Copy + Zero + One + Add<Output=Self> + Mul<Output=Self> {}
+
+impl<T> Semiring for T where T: Copy + Zero + One + Add<Output=T> + Mul<Output=T> {}
+
+/// Types forming a ring: a semiring with subtraction, such as the integers,
+/// the floats, Complex and square matrices over any of them.
+///
+pub trait Ring: Semiring + Sub<Output=Self> {}
+
+impl<T> Ring for T where T: Semiring + Sub<Output=T> {}
+
+/// Types that multiply matrices and vectors as scalars, entry by entry.
+///
+/// Matrix * S and Vector * S are implemented for every Scalar S rather than
+/// only the entry type, so a matrix of lengths can be scaled by a plain f64;
+/// a marker is needed because a blanket impl would overlap Matrix * Matrix.
+///
+/// Only Scalar types multiply in this way, so an entry type from outside
+/// this crate needs an impl of its own for m * x to compile, where any T
+/// with Mul was once enough. The primitives, Complex, Ratio and the
+/// tropical semirings have one.
+///
+pub trait Scalar: Copy {}
+
+macro_rules! impl_zero_one {
+    ($($t:ty, $zero:expr, $one:expr);*) => {
+        $(
+            impl Zero for $t {
+                fn zero() -> $t { $zero }
+            }
+
+            impl One for $t {
+                fn one() -> $t { $one }
+            }
+
+            impl Scalar for $t {}
+        )*
+    }
+}
+
+impl_zero_one!(i8, 0, 1; i16, 0, 1; i32, 0, 1; i64, 0, 1; i128, 0, 1; isize, 0, 1;
+               u8, 0, 1; u16, 0, 1; u32, 0, 1; u64, 0, 1; u128, 0, 1; usize, 0, 1;
+               f32, 0.0, 1.0; f64, 0.0, 1.0);
+
+impl Zero for bool {
+    fn zero() -> bool { false }
+}
+
+impl One for bool {
+    fn one() -> bool { true }
+}
+
+/// Types with a fixed-size little-endian byte encoding.
+pub trait LeBytes: Sized {
+    /// The number of bytes in the encoding.
+    const SIZE: usize;
+
+    /// Appends the encoding of self to bytes.
+    fn write_le(&self, bytes: &mut Vec<u8>);
+
+    /// The value encoded by the first SIZE bytes, or None if there are fewer.
+    fn read_le(bytes: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_le_bytes {
+    ($($t:ty, $size:expr);*) => {
+        $(
+            impl LeBytes for $t {
+                const SIZE: usize = $size;
+
+                fn write_le(&self, bytes: &mut Vec<u8>) {
+                    bytes.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn read_le(bytes: &[u8]) -> Option<$t> {
+                    let mut buffer = [0; $size];
+                    buffer.copy_from_slice(bytes.get(..$size)?);
+
+                    Some(<$t>::from_le_bytes(buffer))
+                }
+            }
+        )*
+    }
+}
+
+impl_le_bytes!(i8, 1; i16, 2; i32, 4; i64, 8; i128, 16;
+               u8, 1; u16, 2; u32, 4; u64, 8; u128, 16;
+               f32, 4; f64, 8);
@@ -0,0 +1,282 @@
+use std::fmt;
+
+use modular::extended_gcd;
+use num::Scalar;
+use Matrix;
+
+/// Represents the fraction numer / denom in lowest terms, with denom > 0.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Ratio {
+    numer: i64,
+    denom: i64
+}
+
+impl Ratio {
+    /// Reduces numer / denom to lowest terms; denom must be non-zero.
+    pub fn new(numer: i64, denom: i64) -> Ratio {
+        assert!(denom != 0, "denominator must be non-zero");
+
+        let (g, _, _) = extended_gcd(numer, denom);
+        let sign = denom.signum();
+
+        Ratio {
+            numer: sign * numer / g,
+            denom: sign * denom / g
+        }
+    }
+
+    pub fn numer(&self) -> i64 {
+        self.numer
+    }
+
+    pub fn denom(&self) -> i64 {
+        self.denom
+    }
+
+    pub fn to_f64(&self) -> f64 {
+        self.numer as f64 / self.denom as f64
+    }
+}
+
+impl Scalar for Ratio {}
+
+impl fmt::Display for Ratio {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.numer, self.denom)
+    }
+}
+
+/// A step down the Stern-Brocot tree.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Direction {
+    Left,
+    Right
+}
+
+/// A position in the Stern-Brocot tree of positive rationals.
+///
+/// Stored as a unimodular matrix [[a, b], [c, d]] whose columns a/c and b/d
+/// bound the subtree; the node itself is their mediant (a + b) / (c + d).
+/// Moving left multiplies on the right by [[1, 0], [1, 1]], moving right by
+/// [[1, 1], [0, 1]].
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SternBrocot {
+    matrix: Matrix<i64>
+}
+
+impl SternBrocot {
+    /// The root, 1/1.
+    pub fn root() -> SternBrocot {
+        SternBrocot {
+            matrix: Matrix::identity()
+        }
+    }
+
+    /// The node reached by single steps in turn.
+    pub fn from_steps(steps: &[Direction]) -> SternBrocot {
+        steps.iter().fold(SternBrocot::root(), |node, &direction| node.step(direction))
+    }
+
+    /// The node reached by the runs of steps (direction, count) in turn, so
+    /// that from_path(&node.path()) is node.
+    ///
+    pub fn from_path(path: &[(Direction, u64)]) -> SternBrocot {
+        path.iter().fold(SternBrocot::root(), |node, &(direction, count)| node.steps(direction, count))
+    }
+
+    /// The node holding r, or None unless r is positive.
+    pub fn from_ratio(r: Ratio) -> Option<SternBrocot> {
+        SternBrocot::path_to(r).map(|path| SternBrocot::from_path(&path))
+    }
+
+    /// The steps from the root to r as runs (direction, count), or None
+    /// unless r is positive.
+    ///
+    /// Read off the continued fraction [a0; a1, ..., an] of r, the path is
+    /// R^a0 L^a1 R^a2 ... with the last exponent one less, so this takes
+    /// time logarithmic in r's numerator and denominator.
+    ///
+    pub fn path_to(r: Ratio) -> Option<Vec<(Direction, u64)>> {
+        if r.numer <= 0 {
+            return None;
+        }
+
+        let (mut p, mut q) = (r.numer, r.denom);
+        let mut terms = Vec::new();
+
+        while q != 0 {
+            terms.push((p / q) as u64);
+            let rem = p % q;
+            p = q;
+            q = rem;
+        }
+        if let Some(last) = terms.last_mut() {
+            *last -= 1;
+        }
+
+        let runs = terms.iter().enumerate()
+            .filter(|&(_, &count)| count > 0)
+            .map(|(k, &count)| (if k % 2 == 0 { Direction::Right } else { Direction::Left }, count))
+            .collect();
+
+        Some(runs)
+    }
+
+    pub fn matrix(&self) -> Matrix<i64> {
+        self.matrix
+    }
+
+    pub fn value(&self) -> Ratio {
+        let m = &self.matrix;
+
+        Ratio::new(m.a + m.b, m.c + m.d)
+    }
+
+    /// The steps from the root to this node, as runs as in path_to.
+    pub fn path(&self) -> Vec<(Direction, u64)> {
+        SternBrocot::path_to(self.value()).unwrap()
+    }
+
+    pub fn left(&self) -> SternBrocot {
+        self.step(Direction::Left)
+    }
+
+    pub fn right(&self) -> SternBrocot {
+        self.step(Direction::Right)
+    }
+
+    pub fn step(&self, direction: Direction) -> SternBrocot {
+        self.steps(direction, 1)
+    }
+
+    /// The node count steps in direction below this one.
+    pub fn steps(&self, direction: Direction, count: u64) -> SternBrocot {
+        let generator = match direction {
+            Direction::Left  => Matrix::new(1, 0, 1, 1),
+            Direction::Right => Matrix::new(1, 1, 0, 1)
+        };
+
+        SternBrocot {
+            matrix: self.matrix * generator.pow(count)
+        }
+    }
+}
+
+/// Remainders this close to an integer are treated as exact, ending the expansion.
+const INTEGER_EPSILON: f64 = 1e-9;
+
+/// Up to depth partial quotients [a0; a1, a2, ...] of the continued fraction of x.
+///
+/// Stops early once a remainder is within rounding error of an integer, so
+/// rationals give their finite expansion. Floating-point error makes terms
+/// beyond roughly the tenth unreliable.
+///
+pub fn continued_fraction(x: f64, depth: usize) -> Vec<i64> {
+    let mut terms = Vec::new();
+    let mut x = x;
+
+    while terms.len() < depth {
+        let nearest = x.round();
+
+        if (x - nearest).abs() < INTEGER_EPSILON * nearest.abs().max(1.0) {
+            terms.push(nearest as i64);
+            break;
+        }
+
+        let a = x.floor();
+        terms.push(a as i64);
+        x = 1.0 / (x - a);
+    }
+
+    terms
+}
+
+/// The product of the matrices [[a, 1], [1, 0]] over the partial quotients a.
+///
+/// For terms [a0; a1, ..., an] this is [[p_n, p_n-1], [q_n, q_n-1]], where
+/// p_k / q_k is the kth convergent.
+///
+pub fn convergent_matrix(terms: &[i64]) -> Matrix<i64> {
+    terms.iter().fold(Matrix::identity(), |acc, &a| acc * Matrix::new(a, 1, 1, 0))
+}
+
+/// The convergents [a0], [a0; a1], ..., [a0; a1, ..., an] of a continued fraction.
+pub fn convergents(terms: &[i64]) -> Vec<Ratio> {
+    let mut product = Matrix::identity();
+
+    terms.iter().map(|&a| {
+        product = product * Matrix::new(a, 1, 1, 0);
+
+        Ratio::new(product.a, product.c)
+    }).collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use number_theory::{continued_fraction, convergent_matrix, convergents, Direction, Ratio, SternBrocot};
+    use Matrix;
+
+    #[test]
+    fn ratios() {
+        assert_eq!(Ratio::new(2, 3), Ratio::new(-4, -6));
+        assert_eq!(-2, Ratio::new(4, -6).numer());
+        assert_eq!(3, Ratio::new(4, -6).denom());
+        assert_eq!("0/1", format!("{}", Ratio::new(0, 5)));
+        assert_eq!(0.75, Ratio::new(3, 4).to_f64());
+    }
+
+    #[test]
+    fn fractions() {
+        assert_eq!(vec![3, 7, 15, 1, 292], continued_fraction(PI, 5));
+        assert_eq!(vec![1, 2, 2, 2, 2, 2], continued_fraction(2.0f64.sqrt(), 6));
+        assert_eq!(vec![2, 3], continued_fraction(2.0 + 1.0 / 3.0, 10));
+
+        assert_eq!(
+            vec![Ratio::new(3, 1), Ratio::new(22, 7), Ratio::new(333, 106), Ratio::new(355, 113)],
+            convergents(&[3, 7, 15, 1])
+        );
+        assert_eq!(Matrix::new(355, 333, 113, 106), convergent_matrix(&[3, 7, 15, 1]));
+
+        // consecutive convergents are neighbours: the product has determinant +-1
+        assert_eq!(1, convergent_matrix(&[3, 7, 15, 1]).determinant());
+    }
+
+    #[test]
+    fn stern_brocot() {
+        use number_theory::Direction::{Left, Right};
+
+        let root = SternBrocot::root();
+
+        assert_eq!(Ratio::new(1, 1), root.value());
+        assert_eq!(Ratio::new(1, 2), root.left().value());
+        assert_eq!(Ratio::new(2, 1), root.right().value());
+        assert_eq!(Ratio::new(3, 5), root.left().right().left().value());
+
+        assert_eq!(Some(vec![(Right, 2), (Left, 1), (Right, 1), (Left, 1)]), SternBrocot::path_to(Ratio::new(13, 5)));
+        assert_eq!(Ratio::new(13, 5), SternBrocot::from_steps(&[Right, Right, Left, Right, Left]).value());
+        assert_eq!(Ratio::new(13, 5), SternBrocot::from_path(&[(Right, 2), (Left, 1), (Right, 1), (Left, 1)]).value());
+        assert_eq!(vec![(Left, 1), (Right, 1), (Left, 1)], root.left().right().left().path());
+        assert_eq!(Vec::<(Direction, u64)>::new(), root.path());
+        assert_eq!(root.right().right().right(), root.steps(Right, 3));
+        assert_eq!(None, SternBrocot::path_to(Ratio::new(-1, 2)));
+
+        for &(p, q) in &[(1, 7), (355, 113), (8, 13)] {
+            let node = SternBrocot::from_ratio(Ratio::new(p, q)).unwrap();
+
+            assert_eq!(Ratio::new(p, q), node.value());
+            assert_eq!(1, node.matrix().determinant());
+            assert_eq!(node, SternBrocot::from_path(&node.path()));
+        }
+
+        // paths of length i64::MAX - 1, walked in a few steps
+        let max = i64::MAX;
+        assert_eq!(Some(vec![(Right, max as u64 - 1)]), SternBrocot::path_to(Ratio::new(max, 1)));
+        assert_eq!(Ratio::new(max, 1), SternBrocot::from_ratio(Ratio::new(max, 1)).unwrap().value());
+        assert_eq!(Ratio::new(1, max), SternBrocot::from_ratio(Ratio::new(1, max)).unwrap().value());
+        assert_eq!(Ratio::new(max, max - 1), SternBrocot::from_ratio(Ratio::new(max, max - 1)).unwrap().value());
+    }
+}
@@ -0,0 +1,208 @@
+use std::iter;
+
+use Diagonal2;
+use EigenEstimate;
+use Matrix;
+use Rotation2;
+use Vector;
+
+/// A linear map acting on values of type V, whether stored densely, as a
+/// structured type such as Rotation2 or Diagonal2, or as a closure.
+///
+/// Algorithms written against this trait, such as power_iteration and
+/// orbit below, work with any of them.
+///
+pub trait LinearOperator<V> {
+    /// The type of the inverse, often Self.
+    type Inverse: LinearOperator<V>;
+
+    fn apply(&self, v: V) -> V;
+
+    /// The inverse operator, or None if there is none or it isn't known.
+    fn inverse(&self) -> Option<Self::Inverse>;
+
+    /// The operator applying inner and then self, like the product
+    /// self * inner.
+    ///
+    fn compose<B>(self, inner: B) -> Composed<Self, B> where Self: Sized, B: LinearOperator<V> {
+        Composed::new(self, inner)
+    }
+}
+
+/// The operator returned by LinearOperator::compose.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Composed<A, B> {
+    outer: A,
+    inner: B
+}
+
+
+impl<A, B> Composed<A, B> {
+    /// The composition applying inner and then outer.
+    pub fn new(outer: A, inner: B) -> Composed<A, B> {
+        Composed {
+            outer,
+            inner
+        }
+    }
+
+    pub fn outer(&self) -> &A {
+        &self.outer
+    }
+
+    pub fn inner(&self) -> &B {
+        &self.inner
+    }
+}
+
+impl<A, B, V> LinearOperator<V> for Composed<A, B> where A: LinearOperator<V>, B: LinearOperator<V> {
+    type Inverse = Composed<B::Inverse, A::Inverse>;
+
+    fn apply(&self, v: V) -> V {
+        self.outer.apply(self.inner.apply(v))
+    }
+
+    fn inverse(&self) -> Option<Composed<B::Inverse, A::Inverse>> {
+        match (self.inner.inverse(), self.outer.inverse()) {
+            (Some(inner), Some(outer)) => Some(inner.compose(outer)),
+            _                          => None
+        }
+    }
+}
+
+impl LinearOperator<Vector<f64>> for Matrix<f64> {
+    type Inverse = Matrix<f64>;
+
+    fn apply(&self, v: Vector<f64>) -> Vector<f64> {
+        *self * v
+    }
+
+    fn inverse(&self) -> Option<Matrix<f64>> {
+        Matrix::inverse(self)
+    }
+}
+
+impl LinearOperator<Vector<f64>> for Rotation2 {
+    type Inverse = Rotation2;
+
+    fn apply(&self, v: Vector<f64>) -> Vector<f64> {
+        *self * v
+    }
+
+    fn inverse(&self) -> Option<Rotation2> {
+        Some(Rotation2::inverse(self))
+    }
+}
+
+impl LinearOperator<Vector<f64>> for Diagonal2<f64> {
+    type Inverse = Diagonal2<f64>;
+
+    fn apply(&self, v: Vector<f64>) -> Vector<f64> {
+        *self * v
+    }
+
+    fn inverse(&self) -> Option<Diagonal2<f64>> {
+        Diagonal2::inverse(self)
+    }
+}
+
+/// Closures are operators with no known inverse.
+impl<F, V> LinearOperator<V> for F where F: Fn(V) -> V {
+    type Inverse = F;
+
+    fn apply(&self, v: V) -> V {
+        self(v)
+    }
+
+    fn inverse(&self) -> Option<F> {
+        None
+    }
+}
+
+/// The orbit x0, A x0, A^2 x0, ... of x0 under op, as an endless iterator.
+pub fn orbit<A, V>(op: A, x0: V) -> impl Iterator<Item=V> where A: LinearOperator<V>, V: Clone {
+    iter::successors(Some(x0), move |x| Some(op.apply(x.clone())))
+}
+
+/// Estimates the eigenpair of largest absolute eigenvalue of op by power
+/// iteration, as Matrix::power_iteration does for a dense matrix.
+///
+/// At least one step is always taken, even when max_iters is 0.
+///
+pub fn power_iteration<A>(op: &A, tol: f64, max_iters: usize) -> EigenEstimate where A: LinearOperator<Vector<f64>> {
+    // at an angle of 1 radian, off the axes and diagonals where the
+    // eigenvectors of simple matrices lie
+    let mut v = Vector::new(1.0f64.cos(), 1.0f64.sin());
+    let mut iterations = 0;
+
+    loop {
+        let w = op.apply(v);
+        iterations += 1;
+
+        // Rayleigh quotient, as v has unit length
+        let eigenvalue = v.dot(w);
+        let residual = (w - v * eigenvalue).length();
+
+        if residual <= tol || iterations >= max_iters || w.dot(w) == 0.0 {
+            return EigenEstimate {
+                eigenvalue,
+                eigenvector: v,
+                iterations,
+                residual,
+                converged: residual <= tol
+            };
+        }
+
+        v = w.normalize();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use operator::{orbit, power_iteration, LinearOperator};
+    use Diagonal2;
+    use Matrix;
+    use Rotation2;
+    use Vector;
+
+    #[test]
+    fn operators() {
+        let shear = Matrix::new(1.0, 2.0, 0.0, 1.0);
+        let quarter = Rotation2::new(::std::f64::consts::FRAC_PI_2);
+        let scale = Diagonal2::new(2.0, 4.0);
+        let v = Vector::new(1.0, 1.0);
+
+        assert_eq!(Vector::new(3.0, 1.0), shear.apply(v));
+        assert_eq!(Vector::new(2.0, 4.0), scale.apply(v));
+
+        let chain = scale.compose(shear);
+        assert_eq!(Vector::new(6.0, 4.0), chain.apply(v));
+        assert_vector_approx_eq!(v, chain.inverse().unwrap().apply(chain.apply(v)));
+        assert_vector_approx_eq!(v, quarter.compose(LinearOperator::inverse(&quarter).unwrap()).apply(v));
+        assert_eq!(None, Matrix::new(1.0, 2.0, 2.0, 4.0).compose(shear).inverse());
+
+        let double = |v: Vector<f64>| v * 2.0;
+        assert_eq!(Vector::new(6.0, 2.0), double.compose(|v: Vector<f64>| v + Vector::new(0.5, -0.5)).apply(Vector::new(2.5, 1.5)));
+        assert!(double.inverse().is_none());
+    }
+
+    #[test]
+    fn algorithms() {
+        let m = Matrix::new(2.0, 1.0, 1.0, 3.0);
+
+        assert_eq!(m.power_iteration(1e-10, 100), power_iteration(&m, 1e-10, 100));
+        assert_eq!(m.orbit(Vector::new(1.0, 0.0)).nth(3), orbit(m, Vector::new(1.0, 0.0)).nth(3));
+
+        let estimate = power_iteration(&Diagonal2::new(0.5, -3.0), 1e-10, 200);
+        assert!(estimate.converged);
+        assert!((estimate.eigenvalue + 3.0).abs() < 1e-9);
+
+        // a matrix-free operator, here the same diagonal as a closure
+        let matrix_free = power_iteration(&|v: Vector<f64>| Vector::new(0.5 * v.x, -3.0 * v.y), 1e-10, 200);
+        assert_eq!(estimate, matrix_free);
+
+        let after_four = orbit(Rotation2::new(::std::f64::consts::FRAC_PI_2), Vector::new(1.0, 0.0)).nth(4).unwrap();
+        assert_vector_approx_eq!(Vector::new(1.0, 0.0), after_four);
+    }
+}
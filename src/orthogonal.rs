@@ -0,0 +1,177 @@
+use std::ops::Mul;
+
+use linear_transforms::rotation;
+use Matrix;
+use Rotation2;
+use UnitVector;
+use UpperTriangular2;
+use Vector;
+
+/// A matrix known to be orthogonal, a rotation or a reflection, so its
+/// inverse is its transpose and its determinant is 1 or -1.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Orthogonal<T> where T: Copy {
+    matrix: Matrix<T>
+}
+
+
+impl<T> Orthogonal<T> where T: Copy {
+    pub fn matrix(&self) -> Matrix<T> {
+        self.matrix
+    }
+
+    /// The inverse, which is the transpose.
+    pub fn inverse(&self) -> Orthogonal<T> {
+        Orthogonal {
+            matrix: self.matrix.transpose()
+        }
+    }
+}
+
+impl Orthogonal<f64> {
+    /// m as an Orthogonal, or None unless m^t m is within epsilon of the
+    /// identity in every entry.
+    ///
+    pub fn new(m: Matrix<f64>, epsilon: f64) -> Option<Orthogonal<f64>> {
+        let p = m.transpose() * m;
+        let off = [p.a - 1.0, p.b, p.c, p.d - 1.0];
+
+        if off.iter().all(|x| x.abs() <= epsilon) {
+            Some(Orthogonal {
+                matrix: m
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The rotation by radians, counter-clockwise.
+    pub fn rotation(radians: f64) -> Orthogonal<f64> {
+        Orthogonal {
+            matrix: rotation(radians)
+        }
+    }
+
+    pub fn from_rotation(r: Rotation2) -> Orthogonal<f64> {
+        Orthogonal::rotation(r.angle())
+    }
+
+    /// The reflection across the line along u.
+    pub fn reflection_across(u: UnitVector<f64>) -> Orthogonal<f64> {
+        Orthogonal {
+            matrix: Matrix::reflection_across(u)
+        }
+    }
+
+    pub fn is_rotation(&self) -> bool {
+        self.matrix.determinant() > 0.0
+    }
+
+    /// 1 for a rotation and -1 for a reflection.
+    pub fn determinant(&self) -> f64 {
+        if self.is_rotation() { 1.0 } else { -1.0 }
+    }
+}
+
+impl Matrix<f64> {
+    /// The QR decomposition self = Q R, returned as (Q, R), with Q a rotation
+    /// and the leading entry of R nonnegative.
+    ///
+    pub fn qr(&self) -> (Orthogonal<f64>, UpperTriangular2<f64>) {
+        let r = self.a.hypot(self.c);
+
+        if r == 0.0 {
+            return (Orthogonal::rotation(0.0), UpperTriangular2::new(0.0, self.b, self.d));
+        }
+
+        let (c, s) = (self.a / r, self.c / r);
+        let q = Orthogonal {
+            matrix: Matrix::new(c, -s, s, c)
+        };
+
+        (q, UpperTriangular2::new(r, c * self.b + s * self.d, c * self.d - s * self.b))
+    }
+
+    /// The polar decomposition self = U P, returned as (U, P), with U
+    /// orthogonal and P symmetric positive semi-definite.
+    ///
+    /// U is unique when self is nonsingular; it is the orthogonal matrix
+    /// nearest to self.
+    ///
+    pub fn polar(&self) -> (Orthogonal<f64>, Matrix<f64>) {
+        let (w, (s1, s2), v) = self.svd();
+        let (v1, v2) = (v.left(), v.right());
+
+        // P = V diag(s1, s2) V^t, symmetric by construction
+        let off = s1 * v1.x * v1.y + s2 * v2.x * v2.y;
+        let p = Matrix::new(s1 * v1.x * v1.x + s2 * v2.x * v2.x, off, off, s1 * v1.y * v1.y + s2 * v2.y * v2.y);
+
+        (Orthogonal { matrix: w * v.transpose() }, p)
+    }
+}
+
+// Operator Methods
+
+/// Implementation of Orthogonal * Orthogonal, applying rhs first.
+impl Mul<Orthogonal<f64>> for Orthogonal<f64> {
+    type Output = Orthogonal<f64>;
+
+    fn mul(self, rhs: Orthogonal<f64>) -> Orthogonal<f64> {
+        Orthogonal {
+            matrix: self.matrix * rhs.matrix
+        }
+    }
+}
+
+/// Implementation of Orthogonal * Vector.
+impl Mul<Vector<f64>> for Orthogonal<f64> {
+    type Output = Vector<f64>;
+
+    fn mul(self, rhs: Vector<f64>) -> Vector<f64> {
+        self.matrix * rhs
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use Matrix;
+    use Orthogonal;
+    use UnitVector;
+    use Vector;
+
+    #[test]
+    fn orthogonal() {
+        let r = Orthogonal::rotation(0.7);
+        let f = Orthogonal::reflection_across(UnitVector::from_angle(0.3));
+        let identity = Matrix::new(1.0, 0.0, 0.0, 1.0);
+
+        assert_matrix_approx_eq!(identity, (r * r.inverse()).matrix());
+        assert_matrix_approx_eq!(identity, (f * f).matrix());
+        assert_eq!(1.0, r.determinant());
+        assert_eq!(-1.0, (r * f).determinant());
+        assert!(Orthogonal::new((r * f * r).matrix(), 1e-12).is_some());
+        assert_eq!(None, Orthogonal::new(Matrix::new(1.0, 1.0, 0.0, 1.0), 1e-12));
+        assert_vector_approx_eq!(Vector::new(0.7f64.cos(), 0.7f64.sin()), r * Vector::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn decompositions() {
+        for m in [Matrix::new(3.0, 1.0, 4.0, 1.0), Matrix::new(-2.0, 5.0, 1.0, 0.5), Matrix::new(0.0, 2.0, 0.0, 3.0)].iter() {
+            let (q, r) = m.qr();
+            assert!(q.is_rotation() && r.to_matrix().a >= 0.0);
+            assert_matrix_approx_eq!(*m, q.matrix() * r.to_matrix());
+
+            let (u, p) = m.polar();
+            assert!(Orthogonal::new(u.matrix(), 1e-12).is_some());
+            assert_eq!(p, p.transpose());
+            assert!(p.trace() >= 0.0 && p.determinant() >= -1e-12);
+            assert_matrix_approx_eq!(*m, u.matrix() * p);
+        }
+
+        // a reflection is its own polar factor
+        let (u, _) = Matrix::new(1.0, 0.0, 0.0, -1.0).polar();
+        assert!(!u.is_rotation());
+    }
+}
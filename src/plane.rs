@@ -0,0 +1,100 @@
+use Matrix4;
+use Vector3;
+
+/// Represents the plane of points p with normal . p + offset = 0.
+///
+/// With a unit normal, signed_distance is the true distance, positive on the
+/// side the normal points to.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Plane {
+    normal: Vector3<f64>,
+    offset: f64
+}
+
+
+impl Plane {
+    pub fn new(normal: Vector3<f64>, offset: f64) -> Plane {
+        Plane {
+            normal,
+            offset
+        }
+    }
+
+    /// The plane through point, perpendicular to normal.
+    pub fn from_point_normal(point: Vector3<f64>, normal: Vector3<f64>) -> Plane {
+        Plane::new(normal, -normal.dot(point))
+    }
+
+    pub fn normal(&self) -> Vector3<f64> {
+        self.normal
+    }
+
+    pub fn offset(&self) -> f64 {
+        self.offset
+    }
+
+    /// The same plane with a unit normal.
+    pub fn normalize(&self) -> Plane {
+        let length = self.normal.length();
+
+        Plane::new(self.normal * (1.0 / length), self.offset / length)
+    }
+
+    pub fn signed_distance(&self, p: Vector3<f64>) -> f64 {
+        self.normal.dot(p) + self.offset
+    }
+
+    /// The image of this plane under the projective map m, or None if m is
+    /// singular.
+    ///
+    /// Planes transform as row vectors by the inverse, [normal offset] * m^-1;
+    /// the result is normalized.
+    ///
+    pub fn transform(&self, m: &Matrix4<f64>) -> Option<Plane> {
+        m.inverse().map(|inverse| {
+            let coefficient = |j: usize| {
+                self.normal.x() * inverse.get(0, j) + self.normal.y() * inverse.get(1, j)
+                    + self.normal.z() * inverse.get(2, j) + self.offset * inverse.get(3, j)
+            };
+
+            Plane::new(Vector3::new(coefficient(0), coefficient(1), coefficient(2)), coefficient(3)).normalize()
+        })
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use linear_transforms::rotation_z;
+    use Isometry3;
+    use Matrix4;
+    use Plane;
+    use Vector3;
+
+    #[test]
+    fn distances() {
+        let plane = Plane::new(Vector3::new(0.0, 0.0, 2.0), -4.0).normalize();
+
+        assert_eq!(Vector3::new(0.0, 0.0, 1.0), plane.normal());
+        assert_eq!(3.0, plane.signed_distance(Vector3::new(7.0, 1.0, 5.0)));
+        assert_eq!(-2.0, plane.signed_distance(Vector3::new(0.0, 0.0, 0.0)));
+        assert_eq!(plane, Plane::from_point_normal(Vector3::new(1.0, 1.0, 2.0), Vector3::new(0.0, 0.0, 1.0)));
+    }
+
+    #[test]
+    fn transforms() {
+        let plane = Plane::from_point_normal(Vector3::new(1.0, 0.0, 0.0), Vector3::new(1.0, 0.0, 0.0));
+        let motion = Isometry3::new(rotation_z(::std::f64::consts::FRAC_PI_2), Vector3::new(0.0, 0.0, 3.0));
+        let moved = plane.transform(&motion.to_matrix4()).unwrap();
+
+        // points on the plane stay on it, and distances are preserved
+        for &p in [Vector3::new(1.0, 5.0, -2.0), Vector3::new(4.0, 0.0, 1.0)].iter() {
+            assert!((plane.signed_distance(p) - moved.signed_distance(motion.transform_point(p))).abs() < 1e-12);
+        }
+        assert!((Vector3::new(0.0, 1.0, 0.0) - moved.normal()).length() < 1e-12);
+
+        let flat = Matrix4::from_rows([[1.0, 0.0, 0.0, 0.0], [0.0, 1.0, 0.0, 0.0], [0.0, 0.0, 0.0, 0.0], [0.0, 0.0, 0.0, 1.0]]);
+        assert_eq!(None, plane.transform(&flat));
+    }
+}
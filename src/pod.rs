@@ -0,0 +1,67 @@
+use bytemuck::{Pod, Zeroable};
+
+use Affine2;
+use Complex;
+use Matrix;
+use Matrix3;
+use Matrix4;
+use Point2;
+use Quaternion;
+use Vector;
+use Vector3;
+
+// Each type is repr(C) with its every field a T, or an array or struct of
+// them, so none has padding and any bit pattern of Pod entries is a value.
+
+unsafe impl<T> Zeroable for Matrix<T> where T: Copy + Zeroable {}
+unsafe impl<T> Pod for Matrix<T> where T: Pod {}
+
+unsafe impl<T> Zeroable for Vector<T> where T: Copy + Zeroable {}
+unsafe impl<T> Pod for Vector<T> where T: Pod {}
+
+unsafe impl<T> Zeroable for Point2<T> where T: Copy + Zeroable {}
+unsafe impl<T> Pod for Point2<T> where T: Pod {}
+
+unsafe impl<T> Zeroable for Vector3<T> where T: Copy + Zeroable {}
+unsafe impl<T> Pod for Vector3<T> where T: Pod {}
+
+unsafe impl<T> Zeroable for Matrix3<T> where T: Copy + Zeroable {}
+unsafe impl<T> Pod for Matrix3<T> where T: Pod {}
+
+unsafe impl<T> Zeroable for Matrix4<T> where T: Copy + Zeroable {}
+unsafe impl<T> Pod for Matrix4<T> where T: Pod {}
+
+unsafe impl<T> Zeroable for Affine2<T> where T: Copy + Zeroable {}
+unsafe impl<T> Pod for Affine2<T> where T: Pod {}
+
+unsafe impl<T> Zeroable for Complex<T> where T: Copy + Zeroable {}
+unsafe impl<T> Pod for Complex<T> where T: Pod {}
+
+unsafe impl Zeroable for Quaternion {}
+unsafe impl Pod for Quaternion {}
+
+
+#[cfg(test)]
+mod tests {
+    use bytemuck::{cast_slice, Zeroable};
+
+    use Affine2;
+    use Matrix;
+    use Matrix4;
+    use Vector;
+
+    #[test]
+    fn casts() {
+        let m = Matrix::new(1.0f32, 2.0, 3.0, 4.0);
+        assert_eq!(&[1.0, 2.0, 3.0, 4.0, 1.0, 2.0, 3.0, 4.0], cast_slice::<Matrix<f32>, f32>(&[m, m]));
+
+        // a Matrix4 uploads column by column
+        let n = Matrix4::from_rows([[1.0f32, 2.0, 3.0, 4.0], [5.0, 6.0, 7.0, 8.0], [9.0, 10.0, 11.0, 12.0], [13.0, 14.0, 15.0, 16.0]]);
+        assert_eq!(&[1.0, 5.0, 9.0, 13.0], &cast_slice::<Matrix4<f32>, f32>(&[n])[..4]);
+
+        let t = Affine2::new(m, Vector::new(5.0, 6.0));
+        assert_eq!(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], cast_slice::<Affine2<f32>, f32>(&[t]));
+
+        assert_eq!(Matrix::new(0, 0, 0, 0), Matrix::<i32>::zeroed());
+    }
+}
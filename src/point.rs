@@ -0,0 +1,124 @@
+use std::fmt;
+use std::fmt::Display;
+use std::ops::{Add, Mul, Sub};
+
+use Matrix;
+use Vector;
+
+/// Represents a position in the plane with coordinates of type T.
+///
+/// Unlike a Vector, a point has no length or direction: the difference of two
+/// points is a Vector, and a Vector may be added to a point to move it.
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[repr(C)]
+pub struct Point2<T> where T: Copy {
+    x: T,
+    y: T
+}
+
+
+// Vanilla Methods
+
+impl<T> Point2<T> where T: Copy {
+    pub fn new(x: T, y: T) -> Point2<T> {
+        Point2 {
+            x,
+            y
+        }
+    }
+
+    /// The point displaced from the origin by v.
+    pub fn from_vector(v: Vector<T>) -> Point2<T> {
+        Point2::new(
+            v.x,
+            v.y
+        )
+    }
+
+    /// The displacement of this point from the origin.
+    pub fn to_vector(&self) -> Vector<T> {
+        Vector::new(
+            self.x,
+            self.y
+        )
+    }
+}
+
+// Display Methods
+
+impl<T> fmt::Display for Point2<T> where T: Copy + Display {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+// Operator Methods
+
+/// Implementation of Point2 - Point2, giving the displacement between them.
+impl<T> Sub<Point2<T>> for Point2<T> where T: Copy + Sub<Output=T> {
+    type Output = Vector<T>;
+
+    fn sub(self, rhs: Point2<T>) -> Vector<T> {
+        Vector::new(
+            self.x - rhs.x,
+            self.y - rhs.y
+        )
+    }
+}
+
+/// Implementation of Point2 + Vector.
+impl<T> Add<Vector<T>> for Point2<T> where T: Copy + Add<Output=T> {
+    type Output = Point2<T>;
+
+    fn add(self, rhs: Vector<T>) -> Point2<T> {
+        Point2::new(
+            self.x + rhs.x,
+            self.y + rhs.y
+        )
+    }
+}
+
+/// Implementation of Point2 - Vector.
+impl<T> Sub<Vector<T>> for Point2<T> where T: Copy + Sub<Output=T> {
+    type Output = Point2<T>;
+
+    fn sub(self, rhs: Vector<T>) -> Point2<T> {
+        Point2::new(
+            self.x - rhs.x,
+            self.y - rhs.y
+        )
+    }
+}
+
+/// Implementation of Matrix * Point2, a linear map fixing the origin.
+impl<T, U, V> Mul<Point2<U>> for Matrix<T> where T: Copy + Mul<U, Output=V>, U: Copy, V: Copy + Add<Output=V> {
+    type Output = Point2<V>;
+
+    fn mul(self, rhs: Point2<U>) -> Point2<V> {
+        Point2::from_vector(self * rhs.to_vector())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use Matrix;
+    use Point2;
+    use Vector;
+
+    #[test]
+    fn affine_algebra() {
+        let p: Point2<i32> = Point2::new(1, 2);
+        let q: Point2<i32> = Point2::new(4, 6);
+        let v: Vector<i32> = Vector::new(3, 4);
+
+        assert_eq!(v,                 q - p);
+        assert_eq!(q,                 p + v);
+        assert_eq!(p,                 q - v);
+        assert_eq!(p,                 Point2::from_vector(p.to_vector()));
+
+        assert_eq!(Point2::new(5, 11), Matrix::new(1, 2, 3, 4) * p);
+        assert_eq!("(1, 2)",           format!("{}", p));
+    }
+}
@@ -0,0 +1,115 @@
+use std::f64::consts::FRAC_1_SQRT_2;
+
+use Complex;
+use Matrix;
+
+/// A single-qubit gate: a 2x2 unitary matrix acting on the amplitudes of
+/// |0> and |1>.
+pub type Gate = Matrix<Complex<f64>>;
+
+const ZERO: Complex<f64> = Complex::new(0.0, 0.0);
+const ONE: Complex<f64> = Complex::new(1.0, 0.0);
+const I: Complex<f64> = Complex::new(0.0, 1.0);
+const H: Complex<f64> = Complex::new(FRAC_1_SQRT_2, 0.0);
+
+pub const PAULI_X: Gate = Matrix { a: ZERO, b: ONE,  c: ONE,  d: ZERO };
+pub const PAULI_Y: Gate = Matrix { a: ZERO, b: Complex::new(0.0, -1.0), c: I, d: ZERO };
+pub const PAULI_Z: Gate = Matrix { a: ONE,  b: ZERO, c: ZERO, d: Complex::new(-1.0, 0.0) };
+
+pub const HADAMARD: Gate = Matrix { a: H, b: H, c: H, d: Complex::new(-FRAC_1_SQRT_2, 0.0) };
+
+/// The phase shift diag(1, e^(i phi)); phi = pi / 2 gives the S gate and
+/// phi = pi / 4 the T gate.
+///
+pub fn phase(phi: f64) -> Gate {
+    Matrix::new(ONE, ZERO, ZERO, Complex::from_polar(1.0, phi))
+}
+
+/// Rotation by theta about the x axis of the Bloch sphere, e^(-i theta X / 2).
+pub fn rx(theta: f64) -> Gate {
+    let (c, s) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+
+    Matrix::new(
+        Complex::new(c, 0.0),  Complex::new(0.0, -s),
+        Complex::new(0.0, -s), Complex::new(c, 0.0)
+    )
+}
+
+/// Rotation by theta about the y axis of the Bloch sphere, e^(-i theta Y / 2).
+pub fn ry(theta: f64) -> Gate {
+    let (c, s) = ((theta / 2.0).cos(), (theta / 2.0).sin());
+
+    Matrix::new(
+        Complex::new(c, 0.0), Complex::new(-s, 0.0),
+        Complex::new(s, 0.0), Complex::new(c, 0.0)
+    )
+}
+
+/// Rotation by theta about the z axis of the Bloch sphere, e^(-i theta Z / 2).
+pub fn rz(theta: f64) -> Gate {
+    Matrix::new(
+        Complex::from_polar(1.0, -theta / 2.0), ZERO,
+        ZERO,                                   Complex::from_polar(1.0, theta / 2.0)
+    )
+}
+
+/// The conjugate transpose.
+pub fn dagger(g: &Gate) -> Gate {
+    Matrix::new(g.a.conj(), g.c.conj(), g.b.conj(), g.d.conj())
+}
+
+/// Whether g^dagger * g is within epsilon of the identity, in Frobenius norm.
+pub fn is_unitary(g: &Gate, epsilon: f64) -> bool {
+    distance(&(dagger(g) * *g), &Matrix::identity()) <= epsilon
+}
+
+/// The gate applying each of gates in turn, the first one first.
+pub fn compose(gates: &[Gate]) -> Gate {
+    gates.iter().fold(Matrix::identity(), |acc, g| *g * acc)
+}
+
+/// The Frobenius norm of g - h.
+pub fn distance(g: &Gate, h: &Gate) -> f64 {
+    let d = *g - *h;
+
+    (d.a.norm_squared() + d.b.norm_squared() + d.c.norm_squared() + d.d.norm_squared()).sqrt()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use quantum::{compose, dagger, distance, is_unitary, phase, rx, ry, rz};
+    use quantum::{HADAMARD, PAULI_X, PAULI_Y, PAULI_Z};
+    use Complex;
+    use Matrix;
+    use Vector;
+
+    #[test]
+    fn gates() {
+        let i = Complex::new(0.0, 1.0);
+
+        for g in [PAULI_X, PAULI_Y, PAULI_Z, HADAMARD, phase(0.4), rx(1.0), ry(-2.0), rz(0.3)].iter() {
+            assert!(is_unitary(g, 1e-12));
+        }
+        assert!(!is_unitary(&Matrix::identity().scale(Complex::new(2.0, 0.0)), 1e-12));
+
+        assert_eq!(PAULI_Z.scale(i), PAULI_X * PAULI_Y);
+        assert!(distance(&Matrix::identity(), &(HADAMARD * HADAMARD)) < 1e-12);
+        assert!(distance(&PAULI_X, &compose(&[HADAMARD, PAULI_Z, HADAMARD])) < 1e-12);
+        assert!(distance(&phase(PI / 2.0), &compose(&[phase(PI / 4.0), phase(PI / 4.0)])) < 1e-12);
+        assert_eq!(PAULI_Y, dagger(&PAULI_Y));
+
+        // rotations by pi are the Pauli gates up to a global phase of -i
+        assert!(distance(&PAULI_X.scale(-i), &rx(PI)) < 1e-12);
+        assert!(distance(&PAULI_Y.scale(-i), &ry(PI)) < 1e-12);
+        assert!(distance(&PAULI_Z.scale(-i), &rz(PI)) < 1e-12);
+
+        // X then H sends |0> to |->
+        let zero = Vector::new(Complex::new(1.0, 0.0), Complex::new(0.0, 0.0));
+        let minus = compose(&[PAULI_X, HADAMARD]) * zero;
+        assert!((minus.x.re() - 0.5f64.sqrt()).abs() < 1e-12);
+        assert!((minus.y.re() + 0.5f64.sqrt()).abs() < 1e-12);
+    }
+}
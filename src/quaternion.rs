@@ -0,0 +1,180 @@
+use std::fmt;
+use std::ops::Mul;
+
+use Matrix3;
+use Vector3;
+
+/// Represents the quaternion w + xi + yj + zk.
+///
+/// Unit quaternions represent 3D rotations, q and -q giving the same one.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[repr(C)]
+pub struct Quaternion {
+    w: f64,
+    x: f64,
+    y: f64,
+    z: f64
+}
+
+
+impl Quaternion {
+    pub fn new(w: f64, x: f64, y: f64, z: f64) -> Quaternion {
+        Quaternion {
+            w,
+            x,
+            y,
+            z
+        }
+    }
+
+    pub fn identity() -> Quaternion {
+        Quaternion::new(1.0, 0.0, 0.0, 0.0)
+    }
+
+    /// The unit quaternion rotating by radians about axis, which need not be
+    /// a unit vector.
+    ///
+    pub fn from_axis_angle(axis: Vector3<f64>, radians: f64) -> Quaternion {
+        let u = axis.normalize() * (radians / 2.0).sin();
+
+        Quaternion::new((radians / 2.0).cos(), u.x(), u.y(), u.z())
+    }
+
+    /// The unit quaternion of the rotation matrix m, by Shepperd's method of
+    /// working from the largest of the four squared components.
+    ///
+    pub fn from_matrix(m: &Matrix3<f64>) -> Quaternion {
+        let e = |i, j| m.get(i, j);
+        let trace = m.trace();
+
+        let q = if trace > e(0, 0) && trace > e(1, 1) && trace > e(2, 2) {
+            let s = 2.0 * (1.0 + trace).sqrt();
+            Quaternion::new(s / 4.0, (e(2, 1) - e(1, 2)) / s, (e(0, 2) - e(2, 0)) / s, (e(1, 0) - e(0, 1)) / s)
+        } else if e(0, 0) > e(1, 1) && e(0, 0) > e(2, 2) {
+            let s = 2.0 * (1.0 + e(0, 0) - e(1, 1) - e(2, 2)).sqrt();
+            Quaternion::new((e(2, 1) - e(1, 2)) / s, s / 4.0, (e(0, 1) + e(1, 0)) / s, (e(0, 2) + e(2, 0)) / s)
+        } else if e(1, 1) > e(2, 2) {
+            let s = 2.0 * (1.0 + e(1, 1) - e(0, 0) - e(2, 2)).sqrt();
+            Quaternion::new((e(0, 2) - e(2, 0)) / s, (e(0, 1) + e(1, 0)) / s, s / 4.0, (e(1, 2) + e(2, 1)) / s)
+        } else {
+            let s = 2.0 * (1.0 + e(2, 2) - e(0, 0) - e(1, 1)).sqrt();
+            Quaternion::new((e(1, 0) - e(0, 1)) / s, (e(0, 2) + e(2, 0)) / s, (e(1, 2) + e(2, 1)) / s, s / 4.0)
+        };
+
+        q.normalize()
+    }
+
+    pub fn w(&self) -> f64 {
+        self.w
+    }
+
+    /// The vector part (x, y, z).
+    pub fn vector(&self) -> Vector3<f64> {
+        Vector3::new(self.x, self.y, self.z)
+    }
+
+    pub fn conjugate(&self) -> Quaternion {
+        Quaternion::new(self.w, -self.x, -self.y, -self.z)
+    }
+
+    pub fn norm(&self) -> f64 {
+        (self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    pub fn normalize(&self) -> Quaternion {
+        let n = self.norm();
+
+        Quaternion::new(self.w / n, self.x / n, self.y / n, self.z / n)
+    }
+
+    /// The rotation matrix of this quaternion, which should have unit norm.
+    pub fn to_matrix(&self) -> Matrix3<f64> {
+        let (w, x, y, z) = (self.w, self.x, self.y, self.z);
+
+        Matrix3::new([
+            [1.0 - 2.0 * (y * y + z * z), 2.0 * (x * y - w * z),       2.0 * (x * z + w * y)      ],
+            [2.0 * (x * y + w * z),       1.0 - 2.0 * (x * x + z * z), 2.0 * (y * z - w * x)      ],
+            [2.0 * (x * z - w * y),       2.0 * (y * z + w * x),       1.0 - 2.0 * (x * x + y * y)]
+        ])
+    }
+
+    /// v rotated by this unit quaternion, q v q*.
+    pub fn rotate(&self, v: Vector3<f64>) -> Vector3<f64> {
+        (*self * Quaternion::new(0.0, v.x(), v.y(), v.z()) * self.conjugate()).vector()
+    }
+}
+
+// Display Methods
+
+impl fmt::Display for Quaternion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}+{}i+{}j+{}k", self.w, self.x, self.y, self.z)
+    }
+}
+
+// Operator Methods
+
+/// Implementation of Quaternion * Quaternion, the Hamilton product.
+impl Mul<Quaternion> for Quaternion {
+    type Output = Quaternion;
+
+    fn mul(self, rhs: Quaternion) -> Quaternion {
+        Quaternion::new(
+            self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w
+        )
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use linear_transforms::rotation_axis_angle;
+    use Matrix3;
+    use Quaternion;
+    use Vector3;
+
+    fn distance(a: Matrix3<f64>, b: Matrix3<f64>) -> f64 {
+        let d = a - b;
+
+        (0..3).map(|i| d.row(i).dot(d.row(i))).sum::<f64>().sqrt()
+    }
+
+    #[test]
+    fn algebra() {
+        let (i, j, k) = (Quaternion::new(0.0, 1.0, 0.0, 0.0), Quaternion::new(0.0, 0.0, 1.0, 0.0), Quaternion::new(0.0, 0.0, 0.0, 1.0));
+
+        assert_eq!(k, i * j);
+        assert_eq!(i, j * k);
+        assert_eq!(Quaternion::new(-1.0, 0.0, 0.0, 0.0), i * i);
+        assert_eq!(5.0, Quaternion::new(1.0, 2.0, 2.0, 4.0).norm());
+        assert_eq!("1+2i+3j+4k", format!("{}", Quaternion::new(1.0, 2.0, 3.0, 4.0)));
+    }
+
+    #[test]
+    fn rotations() {
+        let axis = Vector3::new(1.0, 2.0, -0.5);
+        let q = Quaternion::from_axis_angle(axis, 0.8);
+        let v = Vector3::new(0.3, -1.0, 2.0);
+
+        assert!(distance(rotation_axis_angle(axis, 0.8), q.to_matrix()) < 1e-12);
+        assert!((q.to_matrix() * v - q.rotate(v)).length() < 1e-12);
+
+        let r = Quaternion::from_axis_angle(Vector3::new(0.0, 0.0, 1.0), FRAC_PI_2);
+        assert!((Vector3::new(0.0, 1.0, 0.0) - r.rotate(Vector3::new(1.0, 0.0, 0.0))).length() < 1e-12);
+        assert!(distance(q.to_matrix() * r.to_matrix(), (q * r).to_matrix()) < 1e-12);
+
+        // every branch of from_matrix, including half turns
+        for &(axis, angle) in [(axis, 0.8), (Vector3::new(1.0, 0.0, 0.0), PI), (Vector3::new(0.0, 1.0, 0.0), PI), (Vector3::new(0.0, 0.0, 1.0), PI)].iter() {
+            let m = rotation_axis_angle(axis, angle);
+
+            assert!(distance(m, Quaternion::from_matrix(&m).to_matrix()) < 1e-12);
+        }
+        assert_eq!(Quaternion::identity(), Quaternion::from_matrix(&Matrix3::identity()));
+    }
+}
@@ -0,0 +1,60 @@
+use std::ops::{Add, Mul};
+
+use num::{One, Zero};
+use Matrix;
+use Vector;
+
+/// Term n of the sequence x_{k+2} = p * x_{k+1} + q * x_k, where coeffs = (p, q)
+/// and init = (x_0, x_1).
+///
+/// Computed as a power of the companion matrix [[p, q], [1, 0]], so takes
+/// O(log n) multiplications.
+///
+pub fn solve<T>(coeffs: (T, T), init: (T, T), n: u64) -> T
+    where T: Copy + Zero + One + Add<Output=T> + Mul<Output=T> {
+    let (p, q) = coeffs;
+    let (x0, x1) = init;
+    let companion = Matrix::new(p, q, T::one(), T::zero());
+
+    if n == 0 {
+        return x0;
+    }
+
+    // stopping one power short avoids computing (and overflowing on) x_{n+1}
+    (companion.pow(n - 1) * Vector::new(x1, x0)).x
+}
+
+/// The nth Fibonacci number, with fibonacci(0) = 0.
+///
+/// Overflows for n > 93.
+///
+pub fn fibonacci(n: u64) -> u64 {
+    solve((1, 1), (0, 1), n)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use recurrence::{fibonacci, solve};
+
+    #[test]
+    fn fibonacci_numbers() {
+        let first: Vec<u64> = (0..10).map(fibonacci).collect();
+
+        assert_eq!(vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34], first);
+        assert_eq!(12200160415121876738, fibonacci(93));
+    }
+
+    #[test]
+    fn recurrences() {
+        // Lucas numbers
+        assert_eq!(2,   solve((1, 1), (2, 1), 0));
+        assert_eq!(123, solve((1, 1), (2, 1), 10));
+
+        // powers of two: x_{k+2} = 2 x_{k+1}
+        assert_eq!(1024, solve((2, 0), (1, 2), 10));
+
+        // Pell numbers, over floats
+        assert_eq!(13860.0, solve((2.0, 1.0), (0.0, 1.0), 12));
+    }
+}
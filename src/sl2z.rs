@@ -0,0 +1,114 @@
+use Matrix;
+
+/// The generator [[0, -1], [1, 0]], of order 4, acting on the upper half-plane by z -> -1/z.
+pub const S: Matrix<i64> = Matrix { a: 0, b: -1, c: 1, d: 0 };
+
+/// The generator [[1, 1], [0, 1]], acting on the upper half-plane by z -> z + 1.
+pub const T: Matrix<i64> = Matrix { a: 1, b: 1, c: 0, d: 1 };
+
+/// A letter in a word over the generators S and T.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Generator {
+    S,
+
+    /// The power T^k, for any integer k.
+    T(i64)
+}
+
+impl Generator {
+    pub fn matrix(&self) -> Matrix<i64> {
+        match *self {
+            Generator::S    => S,
+            Generator::T(k) => Matrix::new(1, k, 0, 1)
+        }
+    }
+}
+
+/// Whether m lies in SL(2, Z), the integer matrices with determinant 1.
+pub fn is_sl2z(m: &Matrix<i64>) -> bool {
+    m.determinant() == 1
+}
+
+/// The product of a word, read left to right.
+pub fn evaluate(word: &[Generator]) -> Matrix<i64> {
+    word.iter().fold(Matrix::identity(), |acc, g| acc * g.matrix())
+}
+
+/// A word in S and T whose product is m, or None if m is not in SL(2, Z).
+///
+/// This is the Euclidean algorithm on the first column: each T^k reduces a
+/// modulo c and each S swaps the roles of a and c.
+///
+pub fn decompose(m: &Matrix<i64>) -> Option<Vec<Generator>> {
+    if !is_sl2z(m) {
+        return None;
+    }
+
+    let mut word = Vec::new();
+    let mut rest = *m;
+
+    // invariant: m = evaluate(word) * rest
+    while rest.c != 0 {
+        let k = rest.a.div_euclid(rest.c);
+
+        if k != 0 {
+            word.push(Generator::T(k));
+            rest = Matrix::new(1, -k, 0, 1) * rest;
+        }
+
+        // S^-1 = -S takes [[a, b], [c, d]] to [[c, d], [-a, -b]], shrinking c
+        word.push(Generator::S);
+        rest = Matrix::new(0, 1, -1, 0) * rest;
+    }
+
+    // now rest = +-[[1, b], [0, 1]]; -I is S^2
+    if rest.a == -1 {
+        word.push(Generator::S);
+        word.push(Generator::S);
+        rest = rest * -1;
+    }
+    if rest.b != 0 {
+        word.push(Generator::T(rest.b));
+    }
+
+    Some(word)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use sl2z::{decompose, evaluate, is_sl2z, Generator, S, T};
+    use Matrix;
+
+    #[test]
+    fn generators() {
+        assert!(is_sl2z(&S) && is_sl2z(&T));
+        assert!(!is_sl2z(&Matrix::new(2, 0, 0, 1)));
+
+        assert_eq!(Matrix::<i64>::identity() * -1, S * S);
+        assert_eq!(Matrix::<i64>::identity() * -1, (S * T).pow(3));
+        assert_eq!(Matrix::new(1, 5, 0, 1), T.pow(5));
+        assert_eq!(S * T.pow(3) * S, evaluate(&[Generator::S, Generator::T(3), Generator::S]));
+    }
+
+    #[test]
+    fn decomposition() {
+        let cases = [
+            Matrix::identity(),
+            Matrix::<i64>::identity() * -1,
+            S,
+            T,
+            Matrix::new(1, -4, 0, 1),
+            Matrix::new(2, 1, 1, 1),
+            Matrix::new(-7, 2, 17, -5),
+            Matrix::new(13, 8, 21, 13).transpose() * Matrix::new(5i64, 2, 2, 1)
+        ];
+
+        for m in cases.iter() {
+            assert_eq!(*m, evaluate(&decompose(m).unwrap()));
+        }
+
+        assert_eq!(Some(vec![]), decompose(&Matrix::identity()));
+        assert_eq!(None, decompose(&Matrix::new(1, 2, 3, 4)));
+    }
+}
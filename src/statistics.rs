@@ -0,0 +1,93 @@
+use Matrix;
+use Vector;
+
+/// The mean (centroid) of points, with NaN entries if there are none.
+pub fn mean(points: &[Vector<f64>]) -> Vector<f64> {
+    let sum = points.iter().fold(Vector::new(0.0, 0.0), |acc, &p| acc + p);
+
+    sum * (1.0 / points.len() as f64)
+}
+
+/// The population covariance matrix of points: [[var(x), cov(x, y)], [cov(x, y), var(y)]].
+///
+/// Divides by the number of points; entries are NaN if there are none.
+///
+pub fn covariance(points: &[Vector<f64>]) -> Matrix<f64> {
+    let m = mean(points);
+    let sum = points.iter().fold(Matrix::new(0.0, 0.0, 0.0, 0.0), |acc, &p| {
+        let d = p - m;
+
+        acc + Matrix::new(d.x * d.x, d.x * d.y, d.y * d.x, d.y * d.y)
+    });
+
+    sum * (1.0 / points.len() as f64)
+}
+
+/// Principal component analysis of points.
+///
+/// Returns a rotation matrix whose columns are the principal axes, major axis
+/// first, along with the variance along each axis. As with covariance, every
+/// entry is NaN if there are no points or a coordinate is NaN.
+///
+pub fn pca(points: &[Vector<f64>]) -> (Matrix<f64>, (f64, f64)) {
+    // a covariance matrix is symmetric, so the symmetric solver applies
+    let (_, variances, axes) = covariance(points).symmetric_eigen();
+
+    (axes, variances)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use linear_transforms::rotation;
+    use statistics::{covariance, mean, pca};
+    use Matrix;
+    use Vector;
+
+    #[test]
+    fn moments() {
+        let points = [
+            Vector::new(1.0, 2.0),
+            Vector::new(3.0, 6.0),
+            Vector::new(5.0, 10.0),
+            Vector::new(7.0, 14.0)
+        ];
+
+        assert_eq!(Vector::new(4.0, 8.0), mean(&points));
+        assert_eq!(Matrix::new(5.0, 10.0, 10.0, 20.0), covariance(&points));
+
+        let scattered = [
+            Vector::new(1.0, 0.0),
+            Vector::new(-1.0, 0.0),
+            Vector::new(0.0, 1.0),
+            Vector::new(0.0, -1.0)
+        ];
+        assert_eq!(Matrix::new(0.5, 0.0, 0.0, 0.5), covariance(&scattered));
+
+        assert!(mean(&[]).x.is_nan());
+    }
+
+    #[test]
+    fn principal_components() {
+        // an ellipse of points with semi-axes 2 and 1, turned by 0.5 radians
+        let turn = rotation(0.5);
+        let points: Vec<Vector<f64>> = (0..360).map(|i| {
+            let t = (i as f64).to_radians();
+
+            turn * Vector::new(2.0 * t.cos(), t.sin())
+        }).collect();
+
+        let (axes, (major, minor)) = pca(&points);
+
+        assert!((2.0 - major).abs() < 1e-12);
+        assert!((0.5 - minor).abs() < 1e-12);
+        assert!((1.0 - axes.determinant()).abs() < 1e-12);
+        assert!(axes.left().perp_dot(turn.left()).abs() < 1e-12);
+
+        let (axes, (major, minor)) = pca(&[]);
+        assert!(axes.a.is_nan() && major.is_nan() && minor.is_nan());
+
+        let (axes, (major, _)) = pca(&[Vector::new(1.0, 2.0), Vector::new(f64::NAN, 0.0)]);
+        assert!(axes.a.is_nan() && major.is_nan());
+    }
+}
@@ -0,0 +1,97 @@
+use Matrix;
+use Vector;
+
+/// Tolerance when checking that each row of a stochastic matrix sums to 1.
+const ROW_SUM_EPSILON: f64 = 1e-9;
+
+/// A transition matrix for a two-state Markov chain.
+///
+/// Entry (i, j) is the probability of moving from state i to state j, so each
+/// row is non-negative and sums to 1. Distributions are Vectors whose entries
+/// are the probabilities of being in states 0 and 1.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StochasticMatrix {
+    matrix: Matrix<f64>
+}
+
+
+impl StochasticMatrix {
+    /// Returns None unless the entries are non-negative and each row sums to 1.
+    pub fn new(matrix: Matrix<f64>) -> Option<StochasticMatrix> {
+        let non_negative = matrix.a >= 0.0 && matrix.b >= 0.0 && matrix.c >= 0.0 && matrix.d >= 0.0;
+        let rows_sum_to_one = (matrix.a + matrix.b - 1.0).abs() < ROW_SUM_EPSILON
+            && (matrix.c + matrix.d - 1.0).abs() < ROW_SUM_EPSILON;
+
+        if non_negative && rows_sum_to_one {
+            Some(StochasticMatrix { matrix })
+        } else {
+            None
+        }
+    }
+
+    pub fn matrix(&self) -> Matrix<f64> {
+        self.matrix
+    }
+
+    /// The distribution after one transition from distribution.
+    pub fn step(&self, distribution: Vector<f64>) -> Vector<f64> {
+        self.matrix.transpose() * distribution
+    }
+
+    /// The transitions made over n steps.
+    pub fn n_step(&self, n: u64) -> StochasticMatrix {
+        StochasticMatrix {
+            matrix: self.matrix.pow(n)
+        }
+    }
+
+    /// A distribution left unchanged by step.
+    ///
+    /// When the chain never changes state every distribution is stationary, and
+    /// the uniform one is returned.
+    ///
+    pub fn stationary(&self) -> Vector<f64> {
+        let leave_0 = self.matrix.b;
+        let leave_1 = self.matrix.c;
+        let total = leave_0 + leave_1;
+
+        if total == 0.0 {
+            Vector::new(0.5, 0.5)
+        } else {
+            Vector::new(leave_1 / total, leave_0 / total)
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use Matrix;
+    use StochasticMatrix;
+    use Vector;
+
+    #[test]
+    fn validation() {
+        assert!(StochasticMatrix::new(Matrix::new(0.9, 0.1, 0.5, 0.5)).is_some());
+        assert!(StochasticMatrix::new(Matrix::new(0.9, 0.2, 0.5, 0.5)).is_none());
+        assert!(StochasticMatrix::new(Matrix::new(1.5, -0.5, 0.5, 0.5)).is_none());
+    }
+
+    #[test]
+    fn chains() {
+        let weather = StochasticMatrix::new(Matrix::new(0.9, 0.1, 0.5, 0.5)).unwrap();
+        let sunny = Vector::new(1.0, 0.0);
+
+        assert_eq!(Vector::new(0.9, 0.1), weather.step(sunny));
+        assert_eq!(weather.step(weather.step(sunny)), weather.n_step(2).step(sunny));
+
+        let stationary = weather.stationary();
+        assert!(stationary.distance(Vector::new(5.0 / 6.0, 1.0 / 6.0)) < 1e-12);
+        assert!(stationary.distance(weather.step(stationary)) < 1e-12);
+        assert!(stationary.distance(weather.n_step(100).step(sunny)) < 1e-12);
+
+        let stuck = StochasticMatrix::new(Matrix::identity()).unwrap();
+        assert_eq!(Vector::new(0.5, 0.5), stuck.stationary());
+    }
+}
@@ -0,0 +1,391 @@
+use std::f64::consts::PI;
+
+use linear_transforms::rotation;
+use Affine2;
+use Matrix;
+use Point2;
+use Rotation2;
+use TransformIterator;
+use Vector;
+
+/// The Affine2 of an SVG transform attribute or CSS transform value, or None
+/// if it does not parse.
+///
+/// Accepts matrix, translate, translateX, translateY, scale, scaleX, scaleY,
+/// rotate (with an optional centre), skew, skewX and skewY, separated by
+/// whitespace or commas and composed left to right, so the rightmost applies
+/// first. Angles are in degrees unless given a deg, rad, grad or turn unit,
+/// and lengths may carry px.
+///
+pub fn parse_transform(s: &str) -> Option<Affine2<f64>> {
+    let mut rest = s.trim();
+    let mut t = Affine2::identity();
+
+    if rest == "none" {
+        return Some(t);
+    }
+
+    while !rest.is_empty() {
+        let open = rest.find('(')?;
+        let close = rest.find(')')?;
+        if close < open {
+            return None;
+        }
+
+        let args = arguments(&rest[open + 1..close])?;
+        t = t * function(rest[..open].trim(), &args)?;
+
+        rest = rest[close + 1..].trim_start_matches(|c: char| c.is_whitespace() || c == ',');
+    }
+
+    Some(t)
+}
+
+/// The SVG path data d with every coordinate mapped through t.
+///
+/// The result uses absolute commands only, with H and V becoming L since
+/// lines need not stay axis-aligned. Elliptical arcs get the radii and
+/// rotation of the transformed ellipse, and a reflecting t reverses their
+/// sweep. As SVG renderers do, malformed data is transformed up to the first
+/// error and dropped from there on.
+///
+pub fn transform_path(d: &str, t: &Affine2<f64>) -> String {
+    let mut segments: Vec<String> = Vec::new();
+    let mut command: Option<char> = None;
+    let mut current = Point2::new(0.0, 0.0);
+    let mut start = current;
+    let mut rest = separator(d);
+
+    while !rest.is_empty() {
+        let c = rest.chars().next().unwrap();
+        if c.is_ascii_alphabetic() {
+            command = Some(c);
+            rest = separator(&rest[1..]);
+        }
+
+        let c = match command {
+            Some(c) => c,
+            None => break
+        };
+        // paths must open with a moveto
+        if segments.is_empty() && c != 'M' && c != 'm' {
+            break;
+        }
+
+        if c == 'Z' || c == 'z' {
+            segments.push("Z".to_string());
+            current = start;
+            command = None;
+            continue;
+        }
+
+        let (segment, end, after) = match path_segment(c, rest, current, t) {
+            Some(parsed) => parsed,
+            None => break
+        };
+
+        segments.push(segment);
+        current = end;
+        rest = separator(after);
+
+        // coordinates after a moveto are implicit linetos
+        if c == 'M' || c == 'm' {
+            start = end;
+            command = Some(if c == 'M' { 'L' } else { 'l' });
+        }
+    }
+
+    segments.join(" ")
+}
+
+// the leading number of s and what follows it, by SVG's grammar, where
+// "1-2" and "0.5.5" are each two numbers
+fn number(s: &str) -> Option<(f64, &str)> {
+    let bytes = s.as_bytes();
+    let digits = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    };
+
+    let mut end = 0;
+    if end < bytes.len() && (bytes[end] == b'+' || bytes[end] == b'-') {
+        end += 1;
+    }
+    let start = end;
+    end = digits(end);
+    if end < bytes.len() && bytes[end] == b'.' {
+        end = digits(end + 1);
+    }
+    if end == start || &s[start..end] == "." {
+        return None;
+    }
+
+    // an exponent only counts if digits follow it
+    if end < bytes.len() && (bytes[end] == b'e' || bytes[end] == b'E') {
+        let mut e = end + 1;
+        if e < bytes.len() && (bytes[e] == b'+' || bytes[e] == b'-') {
+            e += 1;
+        }
+        if digits(e) > e {
+            end = digits(e);
+        }
+    }
+
+    s[..end].parse().ok().map(|x| (x, &s[end..]))
+}
+
+// strips leading whitespace and at most one comma
+fn separator(s: &str) -> &str {
+    let s = s.trim_start();
+
+    if let Some(rest) = s.strip_prefix(',') {
+        rest.trim_start()
+    } else {
+        s
+    }
+}
+
+// one segment of command c from current, as (transformed absolute
+// segment, untransformed end point, unparsed remainder)
+fn path_segment<'a>(c: char, s: &'a str, current: Point2<f64>, t: &Affine2<f64>) -> Option<(String, Point2<f64>, &'a str)> {
+    let origin = if c.is_ascii_lowercase() { current.to_vector() } else { Vector::new(0.0, 0.0) };
+    let point = |x: f64, y: f64| Point2::new(x, y) + origin;
+    let show = |p: Point2<f64>| {
+        let q = (*t * p).to_vector();
+        format!("{} {}", q.x, q.y)
+    };
+
+    if c == 'A' || c == 'a' {
+        let (radii, after) = numbers(s, 3)?;
+        let (large_arc, after) = flag(separator(after))?;
+        let (sweep, after) = flag(after)?;
+        let (xy, after) = numbers(after, 2)?;
+        let end = point(xy[0], xy[1]);
+
+        // the arc's ellipse is centre + R(phi) diag(rx, ry) applied to the
+        // unit circle, so the transformed one has the singular values of
+        // L R(phi) diag(rx, ry) as radii and its U as rotation
+        let ellipse = t.linear() * rotation(radii[2].to_radians()) * Matrix::new(radii[0].abs(), 0.0, 0.0, radii[1].abs());
+        let (u, (rx, ry), _) = ellipse.svd();
+        let sweep = sweep != (t.linear().determinant() < 0.0);
+
+        // a half turn leaves an ellipse alone, so report the angle in [0, 180)
+        let phi = (Rotation2::from_matrix(&u).angle().to_degrees() + 180.0) % 180.0;
+
+        let segment = format!("A {} {} {} {} {} {}", rx, ry, phi, large_arc as u8, sweep as u8, show(end));
+        return Some((segment, end, after));
+    }
+
+    let (name, count) = match c.to_ascii_uppercase() {
+        'M' => ('M', 2),
+        'L' | 'H' | 'V' => ('L', 2),
+        'C' => ('C', 6),
+        'S' => ('S', 4),
+        'Q' => ('Q', 4),
+        'T' => ('T', 2),
+        _ => return None
+    };
+
+    let (xy, after) = match c {
+        'H' => number(s).map(|(x, after)| (vec![x, current.to_vector().y], after))?,
+        'h' => number(s).map(|(x, after)| (vec![x, 0.0], after))?,
+        'V' => number(s).map(|(y, after)| (vec![current.to_vector().x, y], after))?,
+        'v' => number(s).map(|(y, after)| (vec![0.0, y], after))?,
+        _ => numbers(s, count)?
+    };
+
+    let points: Vec<Point2<f64>> = xy.chunks(2).map(|p| point(p[0], p[1])).collect();
+    let shown: Vec<String> = points.iter().cloned().transformed_by(t).map(|p| format!("{} {}", p.to_vector().x, p.to_vector().y)).collect();
+
+    Some((format!("{} {}", name, shown.join(" ")), points[points.len() - 1], after))
+}
+
+// count separated numbers from the start of s
+fn numbers(s: &str, count: usize) -> Option<(Vec<f64>, &str)> {
+    let mut xs = Vec::with_capacity(count);
+    let mut rest = s;
+
+    for i in 0..count {
+        let (x, after) = number(rest)?;
+
+        xs.push(x);
+        rest = if i + 1 < count { separator(after) } else { after };
+    }
+
+    Some((xs, rest))
+}
+
+// an arc flag, a single 0 or 1 that needs no separator after it
+fn flag(s: &str) -> Option<(bool, &str)> {
+    let flag = match s.as_bytes().first() {
+        Some(&b'0') => false,
+        Some(&b'1') => true,
+        _ => return None
+    };
+
+    Some((flag, separator(&s[1..])))
+}
+
+// the numbers of an argument list with their units
+fn arguments(s: &str) -> Option<Vec<(f64, &str)>> {
+    let mut args = Vec::new();
+    let mut rest = s.trim();
+    if rest.ends_with(',') {
+        return None;
+    }
+
+    while !rest.is_empty() {
+        let (x, after) = number(rest)?;
+        let unit_length = after.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(after.len());
+
+        args.push((x, &after[..unit_length]));
+        rest = separator(&after[unit_length..]);
+    }
+
+    Some(args)
+}
+
+fn function(name: &str, args: &[(f64, &str)]) -> Option<Affine2<f64>> {
+    let angle = |i: usize| {
+        let (x, unit) = args[i];
+
+        match unit {
+            "" | "deg" => Some(x.to_radians()),
+            "rad" => Some(x),
+            "grad" => Some(x * PI / 200.0),
+            "turn" => Some(x * 2.0 * PI),
+            _ => None
+        }
+    };
+    let length = |i: usize| match args[i].1 {
+        "" | "px" => Some(args[i].0),
+        _ => None
+    };
+    let plain = |i: usize| match args[i].1 {
+        "" => Some(args[i].0),
+        _ => None
+    };
+    let lengths = || args.iter().enumerate().map(|(i, _)| length(i)).collect::<Option<Vec<f64>>>();
+    let plains = || args.iter().enumerate().map(|(i, _)| plain(i)).collect::<Option<Vec<f64>>>();
+
+    let t = match (name, args.len()) {
+        ("matrix", 6) => {
+            let m = plains()?;
+            Affine2::new(Matrix::new(m[0], m[2], m[1], m[3]), Vector::new(m[4], m[5]))
+        },
+        ("translate", 1) | ("translateX", 1) => Affine2::from_translation(Vector::new(length(0)?, 0.0)),
+        ("translate", 2) => {
+            let v = lengths()?;
+            Affine2::from_translation(Vector::new(v[0], v[1]))
+        },
+        ("translateY", 1) => Affine2::from_translation(Vector::new(0.0, length(0)?)),
+        ("scale", 1) => Affine2::from_linear(Matrix::new(plain(0)?, 0.0, 0.0, plain(0)?)),
+        ("scale", 2) => {
+            let s = plains()?;
+            Affine2::from_linear(Matrix::new(s[0], 0.0, 0.0, s[1]))
+        },
+        ("scaleX", 1) => Affine2::from_linear(Matrix::new(plain(0)?, 0.0, 0.0, 1.0)),
+        ("scaleY", 1) => Affine2::from_linear(Matrix::new(1.0, 0.0, 0.0, plain(0)?)),
+        ("rotate", 1) => Affine2::from_linear(rotation(angle(0)?)),
+        ("rotate", 3) => {
+            let centre = Vector::new(length(1)?, length(2)?);
+            Affine2::from_translation(centre) * Affine2::from_linear(rotation(angle(0)?)) * Affine2::from_translation(centre * -1.0)
+        },
+        ("skew", 1) | ("skewX", 1) => Affine2::from_linear(Matrix::new(1.0, angle(0)?.tan(), 0.0, 1.0)),
+        ("skew", 2) => Affine2::from_linear(Matrix::new(1.0, angle(0)?.tan(), angle(1)?.tan(), 1.0)),
+        ("skewY", 1) => Affine2::from_linear(Matrix::new(1.0, 0.0, angle(0)?.tan(), 1.0)),
+        _ => return None
+    };
+
+    Some(t)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use linear_transforms::rotation;
+    use svg::{number, parse_transform, transform_path};
+    use Affine2;
+    use Matrix;
+    use Point2;
+    use Vector;
+
+    fn close(a: Affine2<f64>, b: Affine2<f64>) -> bool {
+        let (m, v) = (a.linear() - b.linear(), a.translation() - b.translation());
+
+        m.frobenius_norm() + v.dot(v).sqrt() < 1e-12
+    }
+
+    #[test]
+    fn numbers() {
+        assert_eq!(Some((-0.5, "")), number("-.5"));
+        assert_eq!(Some((1.0, "-2")), number("1-2"));
+        assert_eq!(Some((0.5, ".5")), number("0.5.5"));
+        assert_eq!(Some((1500.0, "px")), number("1.5e3px"));
+        assert_eq!(Some((2.0, "em")), number("2em"));
+        assert_eq!(None, number("."));
+        assert_eq!(None, number("+x"));
+    }
+
+    #[test]
+    fn parsing() {
+        let a = Affine2::new(Matrix::new(1.0, 2.0, 3.0, 4.0), Vector::new(5.0, -6.5));
+        assert_eq!(Some(a), parse_transform(&a.to_svg_transform()));
+        assert_eq!(Some(a), parse_transform("matrix(1 3 2 4 5-6.5)"));
+
+        assert_eq!(Some(Affine2::identity()), parse_transform("  "));
+        assert_eq!(Some(Affine2::identity()), parse_transform("none"));
+
+        // the rightmost transform applies first
+        let t = parse_transform("translate(10px, 20) scale(2)").unwrap();
+        assert_eq!(Point2::new(12.0, 22.0), t * Point2::new(1.0, 1.0));
+
+        let r = parse_transform("rotate(90, 1, 1)").unwrap();
+        let p = r * Point2::new(2.0, 1.0) - Point2::new(1.0, 2.0);
+        assert!(p.dot(p) < 1e-24);
+
+        assert!(close(parse_transform("rotate(0.25turn)").unwrap(), parse_transform("rotate(90deg)").unwrap()));
+        assert!(close(parse_transform("rotate(3.141592653589793rad)").unwrap(), parse_transform("scale(-1)").unwrap()));
+        assert!(close(Affine2::from_linear(Matrix::new(1.0, 1.0, 0.0, 1.0)), parse_transform("skewX(45)").unwrap()));
+        assert!(close(Affine2::from_linear(Matrix::new(1.0, 0.0, 1.0, 1.0)), parse_transform("skewY(50grad)").unwrap()));
+        assert_eq!(parse_transform("scaleX(2),scaleY(3)"), parse_transform("scale(2 3)"));
+        assert_eq!(parse_transform("translateY(-4)"), parse_transform("translate(0,-4)"));
+
+        for bad in ["scale", "scale(1, 2, 3)", "twirl(1)", "rotate(1em)", "scale(2px)", "translate(1) )(", "translate(1,)"].iter() {
+            assert_eq!(None, parse_transform(bad));
+        }
+    }
+
+    #[test]
+    fn paths() {
+        let shift = Affine2::from_translation(Vector::new(10.0, 20.0));
+
+        assert_eq!("M 11 22 L 13 24 L 14 24 L 14 20 Z", transform_path("M1,2 l2 2 h1 V0 z", &shift));
+        assert_eq!("M 10 20 L 11 21 L 12 20", transform_path("m0 0 1 1 1-1", &shift));
+        assert_eq!("M 10 20 C 11 21 12 22 13 23 S 14 24 15 25 Q 16 26 17 27 T 18 28",
+                   transform_path("M0 0C1 1 2 2 3 3s1 1 2 2Q6 6 7 7T8 8", &shift));
+
+        // everything is dropped after the first error
+        assert_eq!("M 11 22 L 13 24", transform_path("M 1 2 L 3 4 L 5", &shift));
+        assert_eq!("", transform_path("L 1 2", &shift));
+        assert_eq!("", transform_path("", &shift));
+
+        // a circular arc under a non-uniform scale becomes elliptical, and a
+        // reflection reverses it
+        let stretch = Affine2::from_linear(Matrix::new(3.0, 0.0, 0.0, -1.0));
+        assert_eq!("M 0 0 A 6 2 0 1 0 30 0", transform_path("M0 0 A2 2 0 1 1 10 0", &stretch));
+        assert_eq!("M 0 0 A 5 5 0 0 1 50 50 A 5 5 0 1 0 60 60", transform_path("M0 0a5 5 0 0150 50a5,5 0 1,0 10,10", &Affine2::identity()));
+
+        // a turned scale turns the arc's ellipse with it
+        let turned = Affine2::from_linear(rotation(FRAC_PI_2) * Matrix::new(2.0, 0.0, 0.0, 1.0));
+        let arc = transform_path("M0 0 A1 1 0 0 1 1 0", &turned);
+        let numbers: Vec<f64> = arc.split(&[' ', 'M', 'A'][..]).filter(|x| !x.is_empty()).map(|x| x.parse().unwrap()).collect();
+        assert!((2.0 - numbers[2]).abs() < 1e-12 && (1.0 - numbers[3]).abs() < 1e-12);
+        assert!((90.0 - numbers[4]).abs() < 1e-9);
+        assert_eq!(1.0, numbers[6]);
+    }
+}
@@ -0,0 +1,181 @@
+use std::ops::{Add, Mul, Sub};
+
+use LowerTriangular2;
+use Matrix;
+use Orthogonal;
+use Vector;
+
+/// Represents the symmetric matrix [[a, b], [b, d]], storing its three
+/// distinct entries only.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Symmetric2<T> where T: Copy {
+    a: T,
+    b: T,
+    d: T
+}
+
+
+// Vanilla Methods
+
+impl<T> Symmetric2<T> where T: Copy {
+    pub fn new(a: T, b: T, d: T) -> Symmetric2<T> {
+        Symmetric2 {
+            a,
+            b,
+            d
+        }
+    }
+
+    /// m as a Symmetric2, or None unless m.b == m.c exactly.
+    pub fn from_matrix(m: &Matrix<T>) -> Option<Symmetric2<T>> where T: PartialEq {
+        if m.b == m.c {
+            Some(Symmetric2::new(m.a, m.b, m.d))
+        } else {
+            None
+        }
+    }
+
+    pub fn a(&self) -> T {
+        self.a
+    }
+
+    /// The off-diagonal entry.
+    pub fn b(&self) -> T {
+        self.b
+    }
+
+    pub fn d(&self) -> T {
+        self.d
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::new(self.a, self.b, self.b, self.d)
+    }
+
+    pub fn trace(&self) -> T where T: Add<Output=T> {
+        self.a + self.d
+    }
+
+    pub fn determinant(&self) -> T where T: Mul<Output=T> + Sub<Output=T> {
+        self.a * self.d - self.b * self.b
+    }
+
+    /// v^t self v = a x^2 + 2 b x y + d y^2.
+    pub fn quadratic_form(&self, v: Vector<T>) -> T where T: Mul<Output=T> + Add<Output=T> {
+        let bxy = self.b * v.x * v.y;
+
+        self.a * v.x * v.x + bxy + bxy + self.d * v.y * v.y
+    }
+}
+
+// Float Methods
+
+impl Symmetric2<f64> {
+    /// The eigenvalues (l1, l2), larger first.
+    pub fn eigenvalues(&self) -> (f64, f64) {
+        let mean = (self.a + self.d) / 2.0;
+        let radius = ((self.a - self.d) / 2.0).hypot(self.b);
+
+        (mean + radius, mean - radius)
+    }
+
+    /// The eigendecomposition self = Q diag(l1, l2) Q^t, returned as
+    /// ((l1, l2), Q), with l1 >= l2 and Q a rotation whose columns are the
+    /// eigenvectors.
+    ///
+    pub fn eigen(&self) -> ((f64, f64), Orthogonal<f64>) {
+        let theta = self.b.atan2((self.a - self.d) / 2.0) / 2.0;
+
+        (self.eigenvalues(), Orthogonal::rotation(theta))
+    }
+
+    pub fn is_positive_definite(&self) -> bool {
+        self.a > 0.0 && self.determinant() > 0.0
+    }
+
+    /// The Cholesky factor, the lower triangular L with positive diagonal and
+    /// self = L L^t, or None unless self is positive definite.
+    ///
+    pub fn cholesky(&self) -> Option<LowerTriangular2<f64>> {
+        if !self.is_positive_definite() {
+            return None;
+        }
+
+        let l11 = self.a.sqrt();
+        let l21 = self.b / l11;
+
+        Some(LowerTriangular2::new(l11, l21, (self.d - l21 * l21).sqrt()))
+    }
+}
+
+// Operator Methods
+
+/// Implementation of Symmetric2 + Symmetric2.
+impl<T> Add<Symmetric2<T>> for Symmetric2<T> where T: Copy + Add<Output=T> {
+    type Output = Symmetric2<T>;
+
+    fn add(self, rhs: Symmetric2<T>) -> Symmetric2<T> {
+        Symmetric2::new(self.a + rhs.a, self.b + rhs.b, self.d + rhs.d)
+    }
+}
+
+/// Implementation of Symmetric2 * Vector.
+impl<T> Mul<Vector<T>> for Symmetric2<T> where T: Copy + Mul<Output=T> + Add<Output=T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: Vector<T>) -> Vector<T> {
+        Vector::new(
+            self.a * rhs.x + self.b * rhs.y,
+            self.b * rhs.x + self.d * rhs.y
+        )
+    }
+}
+
+/// Implementation of Symmetric2 * Matrix.
+impl<T> Mul<Matrix<T>> for Symmetric2<T> where T: Copy + Mul<Output=T> + Add<Output=T> {
+    type Output = Matrix<T>;
+
+    fn mul(self, rhs: Matrix<T>) -> Matrix<T> {
+        Matrix::from_vectors(self * rhs.left(), self * rhs.right())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::mem::size_of;
+
+    use Matrix;
+    use Symmetric2;
+    use Vector;
+
+    #[test]
+    fn symmetric() {
+        let s = Symmetric2::new(2, 1, 3);
+        let v = Vector::new(1, -2);
+
+        assert_eq!(Matrix::new(2, 1, 1, 3), s.to_matrix());
+        assert_eq!(Some(s), Symmetric2::from_matrix(&s.to_matrix()));
+        assert_eq!(None, Symmetric2::from_matrix(&Matrix::new(2, 1, 0, 3)));
+        assert_eq!(5, s.determinant());
+        assert_eq!(5, s.trace());
+        assert_eq!(v.dot(s.to_matrix() * v), s.quadratic_form(v));
+        assert_eq!(s.to_matrix() * v, s * v);
+        assert_eq!(s.to_matrix() * Matrix::new(1, 2, 3, 4), s * Matrix::new(1, 2, 3, 4));
+        assert_eq!(Symmetric2::new(4, 2, 6), s + s);
+        assert_eq!(3 * size_of::<f64>(), size_of::<Symmetric2<f64>>());
+    }
+
+    #[test]
+    fn eigen() {
+        let s = Symmetric2::new(2.0, 1.5, -1.0);
+        let ((l1, l2), q) = s.eigen();
+
+        assert!(l1 >= l2);
+        assert!((s.trace() - (l1 + l2)).abs() < 1e-12);
+        assert_matrix_approx_eq!(s.to_matrix(), q.matrix() * Matrix::new(l1, 0.0, 0.0, l2) * q.matrix().transpose());
+        assert!(!s.is_positive_definite());
+        assert!(Symmetric2::new(2.0, 1.0, 2.0).is_positive_definite());
+    }
+}
@@ -0,0 +1,167 @@
+use std::ops::Mul;
+
+use linear_transforms::{D4 as D4_MATRICES, D4_PRODUCTS};
+use Matrix;
+
+/// The eight symmetries of the square, each the matrix of the same index in
+/// linear_transforms::D4.
+///
+/// The flips are named for the axis they negate, so FlipX mirrors in the y
+/// axis; Transpose swaps x and y and AntiTranspose swaps them negated.
+///
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum D4 {
+    Identity,
+    Rotate90,
+    Rotate180,
+    Rotate270,
+    FlipX,
+    AntiTranspose,
+    FlipY,
+    Transpose
+}
+
+/// Every element of D4, in matrix order.
+pub const ALL: [D4; 8] = [
+    D4::Identity,
+    D4::Rotate90,
+    D4::Rotate180,
+    D4::Rotate270,
+    D4::FlipX,
+    D4::AntiTranspose,
+    D4::FlipY,
+    D4::Transpose
+];
+
+impl D4 {
+    /// The element with matrix m, or None if m is not a symmetry of the square.
+    pub fn from_matrix(m: &Matrix<i32>) -> Option<D4> {
+        D4_MATRICES.iter().position(|x| x == m).map(|i| ALL[i])
+    }
+
+    pub fn matrix(&self) -> Matrix<i32> {
+        D4_MATRICES[*self as usize]
+    }
+
+    /// Whether this is a rotation rather than a reflection.
+    pub fn preserves_orientation(&self) -> bool {
+        (*self as usize) < 4
+    }
+
+    /// self applied after other.
+    pub fn compose(&self, other: D4) -> D4 {
+        D4::from_matrix(&D4_PRODUCTS[*self as usize][other as usize]).unwrap()
+    }
+
+    pub fn inverse(&self) -> D4 {
+        match *self {
+            D4::Rotate90 => D4::Rotate270,
+            D4::Rotate270 => D4::Rotate90,
+            // the rest are their own inverses
+            x => x
+        }
+    }
+
+    /// The width and height of a w by h grid after this symmetry.
+    pub fn grid_size(&self, w: usize, h: usize) -> (usize, usize) {
+        if self.matrix().a == 0 {
+            (h, w)
+        } else {
+            (w, h)
+        }
+    }
+
+    /// Where the cell (x, y) of a w by h grid lands when the grid is
+    /// transformed, in the grid of size grid_size(w, h).
+    ///
+    /// The matrix acts on cell centres about the centre of the grid, in
+    /// whatever orientation x and y have: with y up, Rotate90 turns the grid
+    /// counter-clockwise, and with y down clockwise.
+    ///
+    /// # Panics
+    ///
+    /// If (x, y) lies outside the grid.
+    ///
+    pub fn apply_to_grid_index(&self, w: usize, h: usize, x: usize, y: usize) -> (usize, usize) {
+        assert!(x < w && y < h, "({}, {}) is outside a {} by {} grid", x, y, w, h);
+
+        // doubled offsets from the centre keep half-cells integral
+        let m = self.matrix();
+        let (px, py) = (2 * x as i64 - (w as i64 - 1), 2 * y as i64 - (h as i64 - 1));
+        let (qx, qy) = (m.a as i64 * px + m.b as i64 * py, m.c as i64 * px + m.d as i64 * py);
+        let (w, h) = self.grid_size(w, h);
+
+        (((qx + w as i64 - 1) / 2) as usize, ((qy + h as i64 - 1) / 2) as usize)
+    }
+}
+
+// Operator Methods
+
+/// Implementation of D4 * D4, applying rhs first.
+impl Mul<D4> for D4 {
+    type Output = D4;
+
+    fn mul(self, rhs: D4) -> D4 {
+        self.compose(rhs)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use symmetry::{ALL, D4};
+    use Matrix;
+    use Vector;
+
+    #[test]
+    fn group() {
+        let v = Vector::new(2, 1);
+
+        for &g in ALL.iter() {
+            assert_eq!(D4::Identity, g * g.inverse());
+            assert_eq!(Some(g), D4::from_matrix(&g.matrix()));
+
+            for &h in ALL.iter() {
+                assert_eq!(g.matrix() * (h.matrix() * v), (g * h).matrix() * v);
+                assert_eq!(g.preserves_orientation() == h.preserves_orientation(), (g * h).preserves_orientation());
+            }
+        }
+
+        assert_eq!(D4::Rotate180, D4::Rotate90 * D4::Rotate90);
+        assert_eq!(D4::Transpose, D4::Rotate90 * D4::FlipY);
+        assert_eq!(Vector::new(1, 2), D4::Transpose.matrix() * v);
+        assert_eq!(None, D4::from_matrix(&Matrix::new(1, 1, 0, 1)));
+    }
+
+    #[test]
+    fn grids() {
+        // a 3 by 2 grid turned a quarter becomes 2 by 3
+        assert_eq!((2, 3), D4::Rotate90.grid_size(3, 2));
+        assert_eq!((1, 0), D4::Rotate90.apply_to_grid_index(3, 2, 0, 0));
+        assert_eq!((0, 2), D4::Rotate90.apply_to_grid_index(3, 2, 2, 1));
+        assert_eq!((0, 1), D4::FlipX.apply_to_grid_index(3, 2, 2, 1));
+        assert_eq!((1, 2), D4::Transpose.apply_to_grid_index(3, 2, 2, 1));
+
+        // every symmetry permutes the cells, and its inverse undoes it
+        for &g in ALL.iter() {
+            let (w, h) = g.grid_size(4, 3);
+            let mut seen = [false; 12];
+
+            for x in 0..4 {
+                for y in 0..3 {
+                    let (u, v) = g.apply_to_grid_index(4, 3, x, y);
+
+                    seen[u + w * v] = true;
+                    assert_eq!((x, y), g.inverse().apply_to_grid_index(w, h, u, v));
+                }
+            }
+            assert!(seen.iter().all(|&s| s));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn outside_grid() {
+        D4::Identity.apply_to_grid_index(2, 2, 2, 0);
+    }
+}
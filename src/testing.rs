@@ -0,0 +1,251 @@
+use std::f64::consts::PI;
+
+use linear_transforms::rotation;
+use Matrix;
+use Vector;
+
+/// The tolerance the assertion macros use when none is given.
+pub const DEFAULT_TOLERANCE: f64 = 1e-12;
+
+/// Asserts that two Matrix<f64> agree entrywise to within a tolerance,
+/// DEFAULT_TOLERANCE if none is given, listing the entries that differ.
+///
+#[macro_export]
+macro_rules! assert_matrix_approx_eq {
+    ($expected:expr, $actual:expr) => {
+        $crate::testing::check_matrix(&$expected, &$actual, $crate::testing::DEFAULT_TOLERANCE)
+            .unwrap_or_else(|message| panic!("{}", message))
+    };
+    ($expected:expr, $actual:expr, $tolerance:expr) => {
+        $crate::testing::check_matrix(&$expected, &$actual, $tolerance)
+            .unwrap_or_else(|message| panic!("{}", message))
+    };
+}
+
+/// Asserts that two Vector<f64> agree entrywise to within a tolerance,
+/// DEFAULT_TOLERANCE if none is given, listing the entries that differ.
+///
+#[macro_export]
+macro_rules! assert_vector_approx_eq {
+    ($expected:expr, $actual:expr) => {
+        $crate::testing::check_vector(&$expected, &$actual, $crate::testing::DEFAULT_TOLERANCE)
+            .unwrap_or_else(|message| panic!("{}", message))
+    };
+    ($expected:expr, $actual:expr, $tolerance:expr) => {
+        $crate::testing::check_vector(&$expected, &$actual, $tolerance)
+            .unwrap_or_else(|message| panic!("{}", message))
+    };
+}
+
+/// Ok if every entry of actual is within tolerance of expected, otherwise
+/// the failure message of assert_matrix_approx_eq.
+///
+pub fn check_matrix(expected: &Matrix<f64>, actual: &Matrix<f64>, tolerance: f64) -> Result<(), String> {
+    let entries = [
+        ("(0, 0)", expected.a, actual.a),
+        ("(0, 1)", expected.b, actual.b),
+        ("(1, 0)", expected.c, actual.c),
+        ("(1, 1)", expected.d, actual.d)
+    ];
+
+    check(&entries, &format!("{}", expected), &format!("{}", actual), "matrices", tolerance)
+}
+
+/// Ok if every entry of actual is within tolerance of expected, otherwise
+/// the failure message of assert_vector_approx_eq.
+///
+pub fn check_vector(expected: &Vector<f64>, actual: &Vector<f64>, tolerance: f64) -> Result<(), String> {
+    let entries = [
+        ("x", expected.x, actual.x),
+        ("y", expected.y, actual.y)
+    ];
+
+    check(&entries, &format!("{}", expected), &format!("{}", actual), "vectors", tolerance)
+}
+
+fn check(entries: &[(&str, f64, f64)], expected: &str, actual: &str, kind: &str, tolerance: f64) -> Result<(), String> {
+    // NaN entries count as differing
+    let differing: Vec<String> = entries.iter()
+        .filter(|&&(_, e, a)| (e - a).abs() > tolerance || (e - a).is_nan())
+        .map(|&(name, e, a)| format!("  {}: expected {}, got {} (off by {})", name, e, a, (e - a).abs()))
+        .collect();
+
+    if differing.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("{} differ by more than {}\n  expected: {}\n    actual: {}\n{}",
+                    kind, tolerance, expected, actual, differing.join("\n")))
+    }
+}
+
+/// A matrix with known eigenvalues and the eigenvectors belonging to them.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct EigenFixture {
+    pub matrix: Matrix<f64>,
+    pub eigenvalues: (f64, f64),
+    pub eigenvectors: (Vector<f64>, Vector<f64>)
+}
+
+/// A deterministic source of test matrices: the same seed always yields the
+/// same sequence, on every platform, so property tests share their inputs.
+///
+/// Matrices are built as U diag(s1, s2) V for rotations U and V, so their
+/// singular values, and hence conditioning, are chosen rather than hoped for.
+///
+#[derive(Clone, Debug)]
+pub struct MatrixGenerator {
+    state: u64
+}
+
+impl MatrixGenerator {
+    pub fn new(seed: u64) -> MatrixGenerator {
+        MatrixGenerator {
+            state: seed
+        }
+    }
+
+    /// The next value uniform on [0, 1), by SplitMix64.
+    pub fn next_f64(&mut self) -> f64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^= z >> 31;
+
+        (z >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// The next value uniform on [low, high).
+    pub fn uniform(&mut self, low: f64, high: f64) -> f64 {
+        low + (high - low) * self.next_f64()
+    }
+
+    /// A matrix with entries uniform on [-1, 1).
+    pub fn any(&mut self) -> Matrix<f64> {
+        Matrix::new(self.uniform(-1.0, 1.0), self.uniform(-1.0, 1.0), self.uniform(-1.0, 1.0), self.uniform(-1.0, 1.0))
+    }
+
+    /// A matrix with singular values in [1, 2], so condition number at most 2.
+    pub fn well_conditioned(&mut self) -> Matrix<f64> {
+        let (s1, s2) = (self.uniform(1.0, 2.0), self.uniform(1.0, 2.0));
+
+        self.with_singular_values(s1, s2)
+    }
+
+    /// A matrix with singular values 1 and epsilon, so condition number
+    /// 1 / epsilon.
+    ///
+    pub fn near_singular(&mut self, epsilon: f64) -> Matrix<f64> {
+        self.with_singular_values(1.0, epsilon)
+    }
+
+    /// A rotation or, with equal odds, a reflection.
+    pub fn orthogonal(&mut self) -> Matrix<f64> {
+        let r = self.rotation();
+
+        if self.next_f64() < 0.5 {
+            r
+        } else {
+            r * Matrix::new(1.0, 0.0, 0.0, -1.0)
+        }
+    }
+
+    /// A symmetric matrix with entries uniform on [-1, 1).
+    pub fn symmetric(&mut self) -> Matrix<f64> {
+        let off_diagonal = self.uniform(-1.0, 1.0);
+
+        Matrix::new(self.uniform(-1.0, 1.0), off_diagonal, off_diagonal, self.uniform(-1.0, 1.0))
+    }
+
+    /// A matrix with distinct real eigenvalues, at least 1/2 apart in [-2, 2],
+    /// and well-conditioned eigenvectors of unit length.
+    ///
+    pub fn eigen_fixture(&mut self) -> EigenFixture {
+        let l1 = self.uniform(-2.0, 1.5);
+        let l2 = l1 + self.uniform(0.5, 2.0 - l1);
+
+        // eigenvectors at least 30 degrees apart
+        let t1 = self.uniform(0.0, PI);
+        let t2 = t1 + self.uniform(PI / 6.0, 5.0 * PI / 6.0);
+        let (v1, v2) = (Vector::new(t1.cos(), t1.sin()), Vector::new(t2.cos(), t2.sin()));
+
+        let p = Matrix::new(v1.x, v2.x, v1.y, v2.y);
+        let matrix = p * Matrix::new(l1, 0.0, 0.0, l2) * p.inverse().unwrap();
+
+        EigenFixture {
+            matrix,
+            eigenvalues: (l1, l2),
+            eigenvectors: (v1, v2)
+        }
+    }
+
+    fn rotation(&mut self) -> Matrix<f64> {
+        let angle = self.uniform(-PI, PI);
+
+        rotation(angle)
+    }
+
+    fn with_singular_values(&mut self, s1: f64, s2: f64) -> Matrix<f64> {
+        self.rotation() * Matrix::new(s1, 0.0, 0.0, s2) * self.rotation()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use testing::{check_matrix, check_vector, MatrixGenerator};
+    use Matrix;
+    use Vector;
+
+    #[test]
+    fn assertions() {
+        let m = Matrix::new(1.0, 2.0, 3.0, 4.0);
+
+        assert_matrix_approx_eq!(m, Matrix::new(1.0, 2.0, 3.0, 4.0 + 1e-13));
+        assert_matrix_approx_eq!(m, Matrix::new(1.1, 2.0, 3.0, 4.0), 0.2);
+        assert_vector_approx_eq!(Vector::new(1.0, 2.0), Vector::new(1.0, 2.0));
+        assert_vector_approx_eq!(Vector::new(1.0, 2.0), Vector::new(1.0, 2.5), 0.5);
+
+        let message = check_matrix(&m, &Matrix::new(1.0, 2.5, 3.0, 4.0), 0.1).unwrap_err();
+        assert!(message.contains("(0, 1): expected 2, got 2.5"));
+        assert!(!message.contains("(0, 0)"));
+
+        assert!(check_vector(&Vector::new(0.0, 0.0), &Vector::new(0.0, f64::NAN), 1.0).is_err());
+    }
+
+    #[test]
+    #[should_panic(expected = "y: expected 2, got 3")]
+    fn failing_assertion() {
+        assert_vector_approx_eq!(Vector::new(1.0, 2.0), Vector::new(1.0, 3.0));
+    }
+
+    #[test]
+    fn generators() {
+        let mut g = MatrixGenerator::new(42);
+        assert_eq!(g.clone().any(), MatrixGenerator::new(42).any());
+        assert!(g.clone().any() != MatrixGenerator::new(43).any());
+
+        let identity = Matrix::new(1.0, 0.0, 0.0, 1.0);
+        for _ in 0..20 {
+            let (_, (s1, s2), _) = g.well_conditioned().svd();
+            assert!(s1 / s2 <= 2.0 + 1e-9);
+
+            let (_, (s1, s2), _) = g.near_singular(1e-8).svd();
+            assert!((1e8 - s1 / s2).abs() < 1e2);
+
+            let q = g.orthogonal();
+            assert_matrix_approx_eq!(identity, q.transpose() * q, 1e-12);
+
+            let s = g.symmetric();
+            assert_eq!(s, s.transpose());
+
+            let fixture = g.eigen_fixture();
+            let (l1, l2) = fixture.eigenvalues;
+            let (v1, v2) = fixture.eigenvectors;
+            assert!(l2 - l1 >= 0.5);
+            assert_vector_approx_eq!(v1 * l1, fixture.matrix * v1, 1e-9);
+            assert_vector_approx_eq!(v2 * l2, fixture.matrix * v2, 1e-9);
+        }
+    }
+}
@@ -0,0 +1,157 @@
+use operator::Composed;
+use Affine2;
+use Isometry2;
+use Matrix;
+use Point2;
+use Rotation2;
+use Vector;
+
+/// A transform of the plane, whatever its representation, so generic code
+/// such as Polygon::transform accepts a Matrix, an Affine2, a Rotation2 or
+/// an Isometry2 alike.
+///
+/// Points are moved by the whole transform, while vectors, being
+/// directions or displacements, only by its linear part.
+///
+pub trait Transform2<T> where T: Copy {
+    /// The type of the inverse, often Self.
+    type Inverse: Transform2<T>;
+
+    fn transform_point(&self, p: Point2<T>) -> Point2<T>;
+
+    fn transform_vector(&self, v: Vector<T>) -> Vector<T>;
+
+    /// The inverse transform, or None if there is none.
+    fn inverse_transform(&self) -> Option<Self::Inverse>;
+
+    /// The transform applying self and then next, like next * self.
+    fn then<B>(self, next: B) -> Composed<B, Self> where Self: Sized, B: Transform2<T> {
+        Composed::new(next, self)
+    }
+}
+
+
+impl<T, A, B> Transform2<T> for Composed<A, B> where T: Copy, A: Transform2<T>, B: Transform2<T> {
+    type Inverse = Composed<B::Inverse, A::Inverse>;
+
+    fn transform_point(&self, p: Point2<T>) -> Point2<T> {
+        self.outer().transform_point(self.inner().transform_point(p))
+    }
+
+    fn transform_vector(&self, v: Vector<T>) -> Vector<T> {
+        self.outer().transform_vector(self.inner().transform_vector(v))
+    }
+
+    fn inverse_transform(&self) -> Option<Composed<B::Inverse, A::Inverse>> {
+        match (self.inner().inverse_transform(), self.outer().inverse_transform()) {
+            (Some(inner), Some(outer)) => Some(Composed::new(inner, outer)),
+            _                          => None
+        }
+    }
+}
+
+impl Transform2<f64> for Matrix<f64> {
+    type Inverse = Matrix<f64>;
+
+    fn transform_point(&self, p: Point2<f64>) -> Point2<f64> {
+        *self * p
+    }
+
+    fn transform_vector(&self, v: Vector<f64>) -> Vector<f64> {
+        *self * v
+    }
+
+    fn inverse_transform(&self) -> Option<Matrix<f64>> {
+        self.inverse()
+    }
+}
+
+impl Transform2<f64> for Affine2<f64> {
+    type Inverse = Affine2<f64>;
+
+    fn transform_point(&self, p: Point2<f64>) -> Point2<f64> {
+        *self * p
+    }
+
+    fn transform_vector(&self, v: Vector<f64>) -> Vector<f64> {
+        *self * v
+    }
+
+    fn inverse_transform(&self) -> Option<Affine2<f64>> {
+        self.inverse()
+    }
+}
+
+impl Transform2<f64> for Rotation2 {
+    type Inverse = Rotation2;
+
+    fn transform_point(&self, p: Point2<f64>) -> Point2<f64> {
+        self.matrix() * p
+    }
+
+    fn transform_vector(&self, v: Vector<f64>) -> Vector<f64> {
+        *self * v
+    }
+
+    fn inverse_transform(&self) -> Option<Rotation2> {
+        Some(self.inverse())
+    }
+}
+
+impl Transform2<f64> for Isometry2 {
+    type Inverse = Isometry2;
+
+    fn transform_point(&self, p: Point2<f64>) -> Point2<f64> {
+        *self * p
+    }
+
+    fn transform_vector(&self, v: Vector<f64>) -> Vector<f64> {
+        *self * v
+    }
+
+    fn inverse_transform(&self) -> Option<Isometry2> {
+        Some(self.inverse())
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use Affine2;
+    use Isometry2;
+    use Matrix;
+    use Point2;
+    use Rotation2;
+    use Transform2;
+    use Vector;
+
+    fn round_trip<M>(t: &M, p: Point2<f64>) -> Point2<f64> where M: Transform2<f64> {
+        t.inverse_transform().unwrap().transform_point(t.transform_point(p))
+    }
+
+    #[test]
+    fn transforms() {
+        let p = Point2::new(1.0, 2.0);
+        let v = Vector::new(1.0, 2.0);
+        let shift = Affine2::from_translation(Vector::new(3.0, -1.0));
+        let quarter = Rotation2::new(FRAC_PI_2);
+        let motion = Isometry2::new(quarter, Vector::new(3.0, -1.0));
+
+        assert_eq!(Point2::new(4.0, 1.0), shift.transform_point(p));
+        assert_eq!(v, shift.transform_vector(v));
+        assert_eq!(Point2::new(2.0, 4.0), Matrix::new(2.0, 0.0, 0.0, 2.0).transform_point(p));
+        assert_vector_approx_eq!(Vector::new(-2.0, 1.0), quarter.transform_point(p).to_vector());
+
+        // rotating then shifting is the rigid motion
+        let chain = quarter.then(shift);
+        assert_vector_approx_eq!(motion.transform_point(p).to_vector(), chain.transform_point(p).to_vector());
+        assert_vector_approx_eq!(motion.transform_vector(v), chain.transform_vector(v));
+
+        assert_vector_approx_eq!(p.to_vector(), round_trip(&chain, p).to_vector());
+        assert_vector_approx_eq!(p.to_vector(), round_trip(&motion, p).to_vector());
+        assert_vector_approx_eq!(p.to_vector(), round_trip(&Affine2::new(Matrix::new(2.0, 1.0, 1.0, 1.0), Vector::new(5.0, 0.0)), p).to_vector());
+        assert!(shift.then(Matrix::new(1.0, 1.0, 1.0, 1.0)).inverse_transform().is_none());
+    }
+}
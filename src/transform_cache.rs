@@ -0,0 +1,133 @@
+use std::cell::Cell;
+
+use Affine2;
+use Matrix;
+use Orthogonal;
+
+/// An affine transform with its derived data, the inverse, the normal
+/// matrix and the polar decomposition of the linear part, each computed
+/// the first time it is asked for and kept until the transform changes.
+///
+/// Changes are detected by a key of the six parameters rounded to
+/// multiples of quantum, so a renderer re-setting a transform every frame
+/// pays for the derived data only when it moves. A change too small to
+/// alter the key keeps the old data; a quantum of zero keys on the exact
+/// values.
+///
+#[derive(Clone, Debug)]
+pub struct TransformCache {
+    transform: Affine2<f64>,
+    quantum: f64,
+    key: [u64; 6],
+    inverse: Cell<Option<Option<Affine2<f64>>>>,
+    normal_matrix: Cell<Option<Option<Matrix<f64>>>>,
+    polar: Cell<Option<(Orthogonal<f64>, Matrix<f64>)>>
+}
+
+
+impl TransformCache {
+    pub fn new(transform: Affine2<f64>, quantum: f64) -> TransformCache {
+        TransformCache {
+            transform,
+            quantum,
+            key: TransformCache::key(&transform, quantum),
+            inverse: Cell::new(None),
+            normal_matrix: Cell::new(None),
+            polar: Cell::new(None)
+        }
+    }
+
+    pub fn transform(&self) -> Affine2<f64> {
+        self.transform
+    }
+
+    /// Replaces the transform, dropping the derived data if its key changed.
+    pub fn set(&mut self, transform: Affine2<f64>) {
+        let key = TransformCache::key(&transform, self.quantum);
+
+        self.transform = transform;
+        if key != self.key {
+            self.key = key;
+            self.inverse.set(None);
+            self.normal_matrix.set(None);
+            self.polar.set(None);
+        }
+    }
+
+    /// Replaces the transform with f applied to it, as in set.
+    pub fn update<F>(&mut self, f: F) where F: FnOnce(Affine2<f64>) -> Affine2<f64> {
+        let transform = f(self.transform);
+
+        self.set(transform);
+    }
+
+    /// The inverse, or None if the transform is singular.
+    pub fn inverse(&self) -> Option<Affine2<f64>> {
+        TransformCache::cached(&self.inverse, || self.transform.inverse())
+    }
+
+    /// As Affine2::normal_matrix.
+    pub fn normal_matrix(&self) -> Option<Matrix<f64>> {
+        TransformCache::cached(&self.normal_matrix, || self.transform.normal_matrix())
+    }
+
+    /// The polar decomposition of the linear part, as Matrix::polar.
+    pub fn polar(&self) -> (Orthogonal<f64>, Matrix<f64>) {
+        TransformCache::cached(&self.polar, || self.transform.linear().polar())
+    }
+
+    fn cached<T, F>(cell: &Cell<Option<T>>, compute: F) -> T where T: Copy, F: FnOnce() -> T {
+        cell.get().unwrap_or_else(|| {
+            let value = compute();
+            cell.set(Some(value));
+
+            value
+        })
+    }
+
+    fn key(t: &Affine2<f64>, quantum: f64) -> [u64; 6] {
+        let (l, v) = (t.linear(), t.translation());
+        // adding 0.0 turns -0.0 into 0.0, so tiny values either side of zero match
+        let quantize = |x: f64| if quantum > 0.0 { ((x / quantum).round() + 0.0).to_bits() } else { x.to_bits() };
+
+        [quantize(l.a), quantize(l.b), quantize(l.c), quantize(l.d), quantize(v.x), quantize(v.y)]
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use Affine2;
+    use Matrix;
+    use TransformCache;
+    use Vector;
+
+    #[test]
+    fn caching() {
+        let t = Affine2::new(Matrix::new(2.0, 1.0, 0.0, 1.0), Vector::new(3.0, -1.0));
+        let mut cache = TransformCache::new(t, 1e-6);
+
+        assert_eq!(t.inverse(), cache.inverse());
+        assert_eq!(t.normal_matrix(), cache.normal_matrix());
+        assert_eq!(t.linear().polar(), cache.polar());
+
+        // a jitter below the quantum keeps the cached inverse
+        let jittered = Affine2::new(t.linear(), Vector::new(3.0 + 1e-9, -1.0));
+        cache.set(jittered);
+        assert_eq!(jittered, cache.transform());
+        assert_eq!(t.inverse(), cache.inverse());
+
+        cache.update(|t| Affine2::from_translation(Vector::new(1.0, 0.0)) * t);
+        assert_eq!(cache.transform().inverse(), cache.inverse());
+
+        cache.set(Affine2::from_linear(Matrix::new(1.0, 2.0, 2.0, 4.0)));
+        assert_eq!(None, cache.inverse());
+        assert_eq!(None, cache.normal_matrix());
+
+        // with no quantum any change counts
+        let mut exact = TransformCache::new(t, 0.0);
+        exact.inverse();
+        exact.set(jittered);
+        assert_eq!(jittered.inverse(), exact.inverse());
+    }
+}
@@ -0,0 +1,75 @@
+use std::ops::Mul;
+
+/// Adds transformed_by to every iterator.
+pub trait TransformIterator: Iterator + Sized {
+    /// Lazily applies transform to each item, as transform * item.
+    ///
+    /// Any matrix-like transform works, a Matrix or an Affine2 among them.
+    /// Note that Affine2 ignores its translation for Vector items, treating
+    /// them as directions; stream Point2 items to have them translated.
+    ///
+    fn transformed_by<M>(self, transform: &M) -> TransformedBy<Self, M> where M: Copy + Mul<Self::Item> {
+        TransformedBy {
+            iter: self,
+            transform: *transform
+        }
+    }
+}
+
+impl<I> TransformIterator for I where I: Iterator {}
+
+/// The iterator returned by TransformIterator::transformed_by.
+#[derive(Clone, Debug)]
+pub struct TransformedBy<I, M> {
+    iter: I,
+    transform: M
+}
+
+impl<I, M> Iterator for TransformedBy<I, M> where I: Iterator, M: Copy + Mul<I::Item> {
+    type Item = M::Output;
+
+    fn next(&mut self) -> Option<M::Output> {
+        self.iter.next().map(|item| self.transform * item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, M> DoubleEndedIterator for TransformedBy<I, M> where I: DoubleEndedIterator, M: Copy + Mul<I::Item> {
+    fn next_back(&mut self) -> Option<M::Output> {
+        self.iter.next_back().map(|item| self.transform * item)
+    }
+}
+
+impl<I, M> ExactSizeIterator for TransformedBy<I, M> where I: ExactSizeIterator, M: Copy + Mul<I::Item> {}
+
+
+#[cfg(test)]
+mod tests {
+    use Affine2;
+    use Matrix;
+    use Point2;
+    use TransformIterator;
+    use Vector;
+
+    #[test]
+    fn transformed_by() {
+        let m = Matrix::new(0, -1, 1, 0);
+        let vectors = [Vector::new(1, 0), Vector::new(0, 2)];
+
+        let turned: Vec<Vector<i32>> = vectors.iter().cloned().transformed_by(&m).collect();
+        assert_eq!(vec![Vector::new(0, 1), Vector::new(-2, 0)], turned);
+
+        let a = Affine2::new(m, Vector::new(10, 20));
+        assert_eq!(vec![Vector::new(0, 1), Vector::new(-2, 0)], vectors.iter().cloned().transformed_by(&a).collect::<Vec<_>>());
+
+        // points pick up the translation, and the adapter chains
+        let mut points = vectors.iter().map(|v| Point2::new(v.x, v.y)).transformed_by(&a).transformed_by(&a);
+        assert_eq!(2, points.len());
+        assert_eq!(Some(Point2::new(-10, 28)), points.next_back());
+        assert_eq!(Some(Point2::new(-11, 30)), points.next());
+        assert_eq!(None, points.next());
+    }
+}
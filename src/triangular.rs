@@ -0,0 +1,227 @@
+use std::ops::{Add, Mul};
+
+use num::Zero;
+use Matrix;
+use Vector;
+
+/// Represents the lower triangular matrix [[a, 0], [c, d]].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LowerTriangular2<T> where T: Copy {
+    a: T,
+    c: T,
+    d: T
+}
+
+/// Represents the upper triangular matrix [[a, b], [0, d]].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UpperTriangular2<T> where T: Copy {
+    a: T,
+    b: T,
+    d: T
+}
+
+
+// Vanilla Methods
+
+impl<T> LowerTriangular2<T> where T: Copy {
+    pub fn new(a: T, c: T, d: T) -> LowerTriangular2<T> {
+        LowerTriangular2 {
+            a,
+            c,
+            d
+        }
+    }
+
+    /// m as a LowerTriangular2, or None unless m.b is zero.
+    pub fn from_matrix(m: &Matrix<T>) -> Option<LowerTriangular2<T>> where T: Zero + PartialEq {
+        if m.b == T::zero() {
+            Some(LowerTriangular2::new(m.a, m.c, m.d))
+        } else {
+            None
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> where T: Zero {
+        Matrix::new(self.a, T::zero(), self.c, self.d)
+    }
+
+    pub fn transpose(&self) -> UpperTriangular2<T> {
+        UpperTriangular2::new(self.a, self.c, self.d)
+    }
+
+    /// The product of the diagonal entries.
+    pub fn determinant(&self) -> T where T: Mul<Output=T> {
+        self.a * self.d
+    }
+}
+
+impl<T> UpperTriangular2<T> where T: Copy {
+    pub fn new(a: T, b: T, d: T) -> UpperTriangular2<T> {
+        UpperTriangular2 {
+            a,
+            b,
+            d
+        }
+    }
+
+    /// m as an UpperTriangular2, or None unless m.c is zero.
+    pub fn from_matrix(m: &Matrix<T>) -> Option<UpperTriangular2<T>> where T: Zero + PartialEq {
+        if m.c == T::zero() {
+            Some(UpperTriangular2::new(m.a, m.b, m.d))
+        } else {
+            None
+        }
+    }
+
+    pub fn to_matrix(&self) -> Matrix<T> where T: Zero {
+        Matrix::new(self.a, self.b, T::zero(), self.d)
+    }
+
+    pub fn transpose(&self) -> LowerTriangular2<T> {
+        LowerTriangular2::new(self.a, self.b, self.d)
+    }
+
+    /// The product of the diagonal entries.
+    pub fn determinant(&self) -> T where T: Mul<Output=T> {
+        self.a * self.d
+    }
+}
+
+// Float Methods
+
+impl LowerTriangular2<f64> {
+    /// The x solving self * x = b by forward substitution, or None if a
+    /// diagonal entry is zero.
+    ///
+    pub fn solve(&self, b: Vector<f64>) -> Option<Vector<f64>> {
+        if self.a == 0.0 || self.d == 0.0 {
+            return None;
+        }
+
+        let x = b.x / self.a;
+
+        Some(Vector::new(x, (b.y - self.c * x) / self.d))
+    }
+}
+
+impl UpperTriangular2<f64> {
+    /// The x solving self * x = b by back substitution, or None if a
+    /// diagonal entry is zero.
+    ///
+    pub fn solve(&self, b: Vector<f64>) -> Option<Vector<f64>> {
+        if self.a == 0.0 || self.d == 0.0 {
+            return None;
+        }
+
+        let y = b.y / self.d;
+
+        Some(Vector::new((b.x - self.b * y) / self.a, y))
+    }
+}
+
+impl Matrix<f64> {
+    /// The LU decomposition self = L U, returned as (L, U), with L unit lower
+    /// triangular, or None if self.a is zero, since no pivoting is done.
+    ///
+    pub fn lu(&self) -> Option<(LowerTriangular2<f64>, UpperTriangular2<f64>)> {
+        if self.a == 0.0 {
+            return None;
+        }
+
+        let l = self.c / self.a;
+
+        Some((LowerTriangular2::new(1.0, l, 1.0), UpperTriangular2::new(self.a, self.b, self.d - l * self.b)))
+    }
+}
+
+// Operator Methods
+
+/// Implementation of LowerTriangular2 * Vector.
+impl<T> Mul<Vector<T>> for LowerTriangular2<T> where T: Copy + Mul<Output=T> + Add<Output=T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: Vector<T>) -> Vector<T> {
+        Vector::new(self.a * rhs.x, self.c * rhs.x + self.d * rhs.y)
+    }
+}
+
+/// Implementation of UpperTriangular2 * Vector.
+impl<T> Mul<Vector<T>> for UpperTriangular2<T> where T: Copy + Mul<Output=T> + Add<Output=T> {
+    type Output = Vector<T>;
+
+    fn mul(self, rhs: Vector<T>) -> Vector<T> {
+        Vector::new(self.a * rhs.x + self.b * rhs.y, self.d * rhs.y)
+    }
+}
+
+/// Implementation of LowerTriangular2 * LowerTriangular2.
+impl<T> Mul<LowerTriangular2<T>> for LowerTriangular2<T> where T: Copy + Mul<Output=T> + Add<Output=T> {
+    type Output = LowerTriangular2<T>;
+
+    fn mul(self, rhs: LowerTriangular2<T>) -> LowerTriangular2<T> {
+        LowerTriangular2::new(self.a * rhs.a, self.c * rhs.a + self.d * rhs.c, self.d * rhs.d)
+    }
+}
+
+/// Implementation of UpperTriangular2 * UpperTriangular2.
+impl<T> Mul<UpperTriangular2<T>> for UpperTriangular2<T> where T: Copy + Mul<Output=T> + Add<Output=T> {
+    type Output = UpperTriangular2<T>;
+
+    fn mul(self, rhs: UpperTriangular2<T>) -> UpperTriangular2<T> {
+        UpperTriangular2::new(self.a * rhs.a, self.a * rhs.b + self.b * rhs.d, self.d * rhs.d)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use LowerTriangular2;
+    use Matrix;
+    use Symmetric2;
+    use UpperTriangular2;
+    use Vector;
+
+    #[test]
+    fn triangular() {
+        let l = LowerTriangular2::new(2, 1, 3);
+        let u = UpperTriangular2::new(1, -2, 4);
+        let v = Vector::new(1, 2);
+
+        assert_eq!(Matrix::new(2, 0, 1, 3), l.to_matrix());
+        assert_eq!(Some(u), UpperTriangular2::from_matrix(&u.to_matrix()));
+        assert_eq!(None, LowerTriangular2::from_matrix(&u.to_matrix()));
+        assert_eq!(l.to_matrix().transpose(), l.transpose().to_matrix());
+        assert_eq!(l.to_matrix() * v, l * v);
+        assert_eq!(u.to_matrix() * v, u * v);
+        assert_eq!(l.to_matrix() * l.to_matrix(), (l * l).to_matrix());
+        assert_eq!(u.to_matrix() * u.to_matrix(), (u * u).to_matrix());
+        assert_eq!(6, l.determinant());
+    }
+
+    #[test]
+    fn substitution() {
+        let l = LowerTriangular2::new(2.0, 1.0, 4.0);
+        let u = UpperTriangular2::new(2.0, 1.0, 4.0);
+        let b = Vector::new(2.0, 9.0);
+
+        assert_eq!(Some(Vector::new(1.0, 2.0)), l.solve(b));
+        assert_eq!(b, u * u.solve(b).unwrap());
+        assert_eq!(None, UpperTriangular2::new(1.0, 1.0, 0.0).solve(b));
+    }
+
+    #[test]
+    fn factorizations() {
+        let m = Matrix::new(4.0, 3.0, 6.0, 3.0);
+        let (l, u) = m.lu().unwrap();
+        assert_eq!(m, l.to_matrix() * u.to_matrix());
+        assert_eq!(None, Matrix::new(0.0, 1.0, 1.0, 0.0).lu());
+
+        let (_, r) = m.qr();
+        assert_matrix_approx_eq!(m.transpose() * m, r.transpose().to_matrix() * r.to_matrix());
+
+        let s = Symmetric2::new(4.0, 2.0, 5.0);
+        let c = s.cholesky().unwrap();
+        assert_eq!(s.to_matrix(), c.to_matrix() * c.transpose().to_matrix());
+        assert_eq!(None, Symmetric2::new(1.0, 2.0, 1.0).cholesky());
+    }
+}
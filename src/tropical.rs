@@ -0,0 +1,182 @@
+use std::fmt;
+use std::ops::{Add, Mul};
+
+use num::{One, Scalar, Zero};
+use Matrix;
+
+/// An element of the min-plus semiring, where addition takes the minimum
+/// and multiplication adds, with infinity as zero and 0 as one.
+///
+/// For a matrix W of edge weights, with infinity for missing edges, entry
+/// (i, j) of W.pow(k) is the weight of the lightest walk from i to j with
+/// exactly k edges.
+///
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct MinPlus {
+    value: f64
+}
+
+/// An element of the max-plus semiring, where addition takes the maximum
+/// and multiplication adds, with negative infinity as zero and 0 as one.
+///
+/// Matrix powers give heaviest walks, as in longest paths and scheduling.
+///
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd)]
+pub struct MaxPlus {
+    value: f64
+}
+
+
+impl MinPlus {
+    pub fn new(value: f64) -> MinPlus {
+        MinPlus {
+            value
+        }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl MaxPlus {
+    pub fn new(value: f64) -> MaxPlus {
+        MaxPlus {
+            value
+        }
+    }
+
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+}
+
+impl Zero for MinPlus {
+    fn zero() -> MinPlus {
+        MinPlus::new(f64::INFINITY)
+    }
+}
+
+impl One for MinPlus {
+    fn one() -> MinPlus {
+        MinPlus::new(0.0)
+    }
+}
+
+impl Zero for MaxPlus {
+    fn zero() -> MaxPlus {
+        MaxPlus::new(f64::NEG_INFINITY)
+    }
+}
+
+impl One for MaxPlus {
+    fn one() -> MaxPlus {
+        MaxPlus::new(0.0)
+    }
+}
+
+impl Scalar for MinPlus {}
+
+impl Scalar for MaxPlus {}
+
+/// The weights of the lightest paths between the two vertices of a graph
+/// with edge weights w, infinity marking missing edges, or None if a
+/// negative cycle makes them unbounded.
+///
+pub fn shortest_paths(w: &Matrix<f64>) -> Option<Matrix<f64>> {
+    let m = Matrix::new(MinPlus::new(w.a), MinPlus::new(w.b), MinPlus::new(w.c), MinPlus::new(w.d));
+
+    // with the empty walk allowed, walks of at most one edge cover every
+    // path in a two-vertex graph
+    let d = Matrix::identity() + m;
+    if (d * d) != d {
+        return None;
+    }
+
+    Some(Matrix::new(d.a.value, d.b.value, d.c.value, d.d.value))
+}
+
+// Display Methods
+
+impl fmt::Display for MinPlus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+impl fmt::Display for MaxPlus {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+// Operator Methods
+
+/// Implementation of MinPlus + MinPlus, the minimum.
+impl Add<MinPlus> for MinPlus {
+    type Output = MinPlus;
+
+    fn add(self, rhs: MinPlus) -> MinPlus {
+        MinPlus::new(self.value.min(rhs.value))
+    }
+}
+
+/// Implementation of MinPlus * MinPlus, the sum.
+impl Mul<MinPlus> for MinPlus {
+    type Output = MinPlus;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: MinPlus) -> MinPlus {
+        MinPlus::new(self.value + rhs.value)
+    }
+}
+
+/// Implementation of MaxPlus + MaxPlus, the maximum.
+impl Add<MaxPlus> for MaxPlus {
+    type Output = MaxPlus;
+
+    fn add(self, rhs: MaxPlus) -> MaxPlus {
+        MaxPlus::new(self.value.max(rhs.value))
+    }
+}
+
+/// Implementation of MaxPlus * MaxPlus, the sum.
+impl Mul<MaxPlus> for MaxPlus {
+    type Output = MaxPlus;
+
+    #[allow(clippy::suspicious_arithmetic_impl)]
+    fn mul(self, rhs: MaxPlus) -> MaxPlus {
+        MaxPlus::new(self.value + rhs.value)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use tropical::{shortest_paths, MaxPlus, MinPlus};
+    use Matrix;
+    use Vector;
+
+    #[test]
+    fn walks() {
+        let w = Matrix::new(MinPlus::new(5.0), MinPlus::new(1.0), MinPlus::new(2.0), MinPlus::new(f64::INFINITY));
+
+        // 0 -> 1 -> 0 weighs 3, beating the loop at 0
+        assert_eq!(Matrix::new(MinPlus::new(3.0), MinPlus::new(6.0), MinPlus::new(7.0), MinPlus::new(3.0)), w.pow(2));
+        assert_eq!(Matrix::identity(), w.pow(0));
+        assert_eq!("[[3 6], [7 3]]", format!("{}", w.pow(2)));
+
+        // scaling by a semiring element adds it to every weight
+        assert_eq!(Matrix::new(MinPlus::new(6.0), MinPlus::new(2.0), MinPlus::new(3.0), MinPlus::new(f64::INFINITY)), w * MinPlus::new(1.0));
+        assert_eq!(Vector::new(MaxPlus::new(1.0), MaxPlus::new(-1.0)), Vector::new(MaxPlus::new(3.0), MaxPlus::new(1.0)) * MaxPlus::new(-2.0));
+
+        let h = Matrix::new(MaxPlus::new(5.0), MaxPlus::new(1.0), MaxPlus::new(2.0), MaxPlus::new(-f64::INFINITY));
+        assert_eq!(Matrix::new(MaxPlus::new(10.0), MaxPlus::new(6.0), MaxPlus::new(7.0), MaxPlus::new(3.0)), h.pow(2));
+    }
+
+    #[test]
+    fn paths() {
+        assert_eq!(Some(Matrix::new(0.0, 4.0, f64::INFINITY, 0.0)), shortest_paths(&Matrix::new(7.0, 4.0, f64::INFINITY, f64::INFINITY)));
+        assert_eq!(None, shortest_paths(&Matrix::new(0.0, 1.0, -3.0, 0.0)));
+    }
+}
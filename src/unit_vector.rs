@@ -0,0 +1,124 @@
+use std::ops::Neg;
+
+use Vector;
+
+/// A vector known to have unit length, so APIs taking directions need not
+/// normalize or check them again.
+///
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnitVector<T> where T: Copy {
+    vector: Vector<T>
+}
+
+
+impl<T> UnitVector<T> where T: Copy {
+    pub fn vector(&self) -> Vector<T> {
+        self.vector
+    }
+
+    pub fn x(&self) -> T {
+        self.vector.x
+    }
+
+    pub fn y(&self) -> T {
+        self.vector.y
+    }
+
+    /// This direction turned a quarter counter-clockwise.
+    pub fn perp(&self) -> UnitVector<T> where T: Neg<Output=T> {
+        UnitVector {
+            vector: Vector::new(-self.vector.y, self.vector.x)
+        }
+    }
+}
+
+impl UnitVector<f64> {
+    /// The direction of v, or None if v is zero or not finite.
+    pub fn new_normalize(v: Vector<f64>) -> Option<UnitVector<f64>> {
+        let length = v.length();
+
+        if length == 0.0 || !length.is_finite() {
+            return None;
+        }
+
+        Some(UnitVector {
+            vector: v * (1.0 / length)
+        })
+    }
+
+    /// v itself, or None unless its length is within epsilon of 1.
+    pub fn new(v: Vector<f64>, epsilon: f64) -> Option<UnitVector<f64>> {
+        if (v.length() - 1.0).abs() <= epsilon {
+            Some(UnitVector {
+                vector: v
+            })
+        } else {
+            None
+        }
+    }
+
+    /// The direction at radians counter-clockwise from the x axis.
+    pub fn from_angle(radians: f64) -> UnitVector<f64> {
+        UnitVector {
+            vector: Vector::new(radians.cos(), radians.sin())
+        }
+    }
+
+    /// The angle counter-clockwise from the x axis, in radians within (-pi, pi].
+    pub fn angle(&self) -> f64 {
+        self.vector.y.atan2(self.vector.x)
+    }
+}
+
+// Operator Methods
+
+/// Implementation of -UnitVector.
+impl<T> Neg for UnitVector<T> where T: Copy + Neg<Output=T> {
+    type Output = UnitVector<T>;
+
+    fn neg(self) -> UnitVector<T> {
+        UnitVector {
+            vector: Vector::new(-self.vector.x, -self.vector.y)
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_PI_2;
+
+    use linear_transforms::rotation;
+    use Matrix;
+    use Rotation2;
+    use UnitVector;
+    use Vector;
+
+    #[test]
+    fn unit_vectors() {
+        let u = UnitVector::new_normalize(Vector::new(3.0, 4.0)).unwrap();
+
+        assert_vector_approx_eq!(Vector::new(0.6, 0.8), u.vector());
+        assert_vector_approx_eq!(Vector::new(-0.8, 0.6), u.perp().vector());
+        assert_vector_approx_eq!(Vector::new(-0.6, -0.8), (-u).vector());
+        assert!((FRAC_PI_2 - UnitVector::from_angle(FRAC_PI_2).angle()).abs() < 1e-15);
+
+        assert_eq!(None, UnitVector::new_normalize(Vector::new(0.0, 0.0)));
+        assert_eq!(None, UnitVector::new(Vector::new(1.0, 1.0), 1e-12));
+        assert_eq!(Some(u), UnitVector::new(u.vector(), 0.0));
+    }
+
+    #[test]
+    fn constructors() {
+        let u = UnitVector::new_normalize(Vector::new(3.0, 4.0)).unwrap();
+        let v = Vector::new(2.0, -1.0);
+
+        assert!((v.project_onto(u.vector()) - Matrix::projection_onto(u) * v).length() < 1e-15);
+        assert!((v.reflect(u.perp().vector()) - Matrix::reflection_across(u) * v).length() < 1e-15);
+        assert!((Matrix::householder(u.vector()) - Matrix::reflection_across(u.perp())).frobenius_norm() < 1e-15);
+
+        let r = Rotation2::from_direction(u);
+        assert!((u.vector() - r * Vector::new(1.0, 0.0)).length() < 1e-15);
+        assert!((rotation(u.angle()) - r.matrix()).frobenius_norm() < 1e-15);
+    }
+}